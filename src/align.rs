@@ -1,21 +1,46 @@
 //! Alignment checking primitives.
 
 
-use core::mem::{align_of, size_of};
 use self::super::error::UnalignedError;
+use core::mem::{align_of, size_of};
+use core::ops::{DerefMut, Deref};
+use core::{cmp, fmt};
 
 
+/// The amount of bytes to skip from the start of the given data slice
+/// for it to become aligned for reading and writing `T`s.
+///
+/// This depends only on the alignment of `T`, not on its size,
+/// and is always less than `align_of::<T>()`.
 fn validate_alignment<S, T>(data: &[S]) -> Result<(), usize> {
-    // TODO this could probably become more efficient once `ptr::align_offset`
-    // is stabilized (#44488)
-    let ptr = data.as_ptr();
-    let offset = ptr as usize % align_of::<T>();
-    if offset > 0 {
-        // reverse the offset (from "bytes to insert" to "bytes to remove")
-        Err(size_of::<T>() - offset)
-    } else {
-        Ok(())
+    // Byte pointers can always be aligned by offsetting them, so this never yields `usize::MAX`
+    match (data.as_ptr() as *const u8).align_offset(align_of::<T>()) {
+        0 => Ok(()),
+        offset => Err(offset),
+    }
+}
+
+/// The lengths, in `S`s, of the prefix of the given data slice before its first element aligned
+/// for reading and writing `T`s, and of the middle holding whole `T`s right after it.
+///
+/// The prefix is the shortest possible, and the whole slice if none of its elements are suitably aligned.
+/// For a slice of bytes, it is thus always shorter than `align_of::<T>()` or the slice itself.
+pub(crate) fn aligned_split<S, T>(data: &[S]) -> (usize, usize) {
+    let prefix = cmp::min(data.as_ptr().align_offset(align_of::<T>()), data.len());
+    if size_of::<S>() == 0 || size_of::<T>() == 0 {
+        return (prefix, 0);
+    }
+
+    // The middle has to end on a boundary between both `S`s and `T`s
+    let (mut a, mut b) = (size_of::<S>(), size_of::<T>());
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
     }
+    let step = size_of::<T>() / a;
+    let middle = (data.len() - prefix) / step * step;
+    (prefix, middle)
 }
 
 
@@ -44,3 +69,145 @@ pub fn check_alignment_mut<S, T>(data: &mut [S]) -> Result<&mut [S], UnalignedEr
         Err(off) => Err(UnalignedError::new(off, data)),
     }
 }
+
+
+/// A value of type `B`, stored at an address aligned to at least the alignment of `A`.
+///
+/// This is most useful for byte arrays in stack buffers and `static` tables,
+/// which can then be viewed as slices of any type `T` the alignment `A` is sufficient for,
+/// with no possibility of an [`Error::Unaligned`](../error/enum.Error.html#variant.Unaligned)
+/// (see [`transmute_many_aligned()`](../fn.transmute_many_aligned.html) and its siblings).
+///
+/// `A` is usually one of [`Align2`](struct.Align2.html) through [`Align64`](struct.Align64.html),
+/// or [`AlignOf<T>`](struct.AlignOf.html). `A` itself is never stored.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::{Aligned, Align8};
+/// # use std::mem::align_of;
+/// static TABLE: Aligned<Align8, [u8; 16]> = Aligned::new([0; 16]);
+///
+/// assert!(align_of::<Aligned<Align8, [u8; 16]>>() >= 8);
+/// assert_eq!((TABLE.data.as_ptr() as usize) % 8, 0);
+///
+/// let bytes: &Aligned<Align8, [u8]> = &TABLE;
+/// assert_eq!(bytes.as_bytes(), &[0; 16]);
+/// ```
+#[repr(C)]
+pub struct Aligned<A, B: ?Sized> {
+    alignment: [A; 0],
+    /// The aligned value.
+    pub data: B,
+}
+
+impl<A, B> Aligned<A, B> {
+    /// Wrap the given value, aligning it to `A`.
+    pub const fn new(data: B) -> Aligned<A, B> {
+        Aligned {
+            alignment: [],
+            data,
+        }
+    }
+
+    /// Unwrap the aligned value.
+    pub fn into_inner(self) -> B {
+        self.data
+    }
+}
+
+impl<A> Aligned<A, [u8]> {
+    /// View the aligned bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Mutably view the aligned bytes.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+impl<A, B: ?Sized> Deref for Aligned<A, B> {
+    type Target = B;
+
+    fn deref(&self) -> &B {
+        &self.data
+    }
+}
+
+impl<A, B: ?Sized> DerefMut for Aligned<A, B> {
+    fn deref_mut(&mut self) -> &mut B {
+        &mut self.data
+    }
+}
+
+impl<A, B: Default> Default for Aligned<A, B> {
+    fn default() -> Aligned<A, B> {
+        Aligned::new(B::default())
+    }
+}
+
+impl<A, B: Clone> Clone for Aligned<A, B> {
+    fn clone(&self) -> Aligned<A, B> {
+        Aligned::new(self.data.clone())
+    }
+}
+
+impl<A: Copy, B: Copy> Copy for Aligned<A, B> {}
+
+impl<A, B: ?Sized + PartialEq> PartialEq for Aligned<A, B> {
+    fn eq(&self, other: &Aligned<A, B>) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<A, B: ?Sized + Eq> Eq for Aligned<A, B> {}
+
+impl<A, B: ?Sized + fmt::Debug> fmt::Debug for Aligned<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Aligned")
+            .field("align", &align_of::<A>())
+            .field("data", &&self.data)
+            .finish()
+    }
+}
+
+
+/// Marker for alignment types sufficient for reading and writing a `T`.
+///
+/// # Safety
+///
+/// It is only safe to implement `AlignmentFor<T>` for a type `A` if
+/// `align_of::<A>() >= align_of::<T>()` on every target.
+pub unsafe trait AlignmentFor<T> {}
+
+/// Alignment type with the same alignment as `T`.
+#[derive(Debug, Clone, Copy)]
+pub struct AlignOf<T>([T; 0]);
+
+unsafe impl<T> AlignmentFor<T> for AlignOf<T> {}
+
+macro_rules! align_types {
+    ($($name:ident($align:expr) for $($target:ty)*;)*) => {
+        $(
+            #[doc = concat!("Alignment type of ", stringify!($align), " bytes.")]
+            #[repr(align($align))]
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name;
+
+            unsafe impl AlignmentFor<u8> for $name {}
+            unsafe impl AlignmentFor<i8> for $name {}
+            $(unsafe impl AlignmentFor<$target> for $name {})*
+        )*
+    }
+}
+
+align_types! {
+    Align2(2) for u16 i16;
+    Align4(4) for u16 i16 u32 i32 f32;
+    Align8(8) for u16 i16 u32 i32 f32 u64 i64 f64 usize isize;
+    Align16(16) for u16 i16 u32 i32 f32 u64 i64 f64 usize isize u128 i128;
+    Align32(32) for u16 i16 u32 i32 f32 u64 i64 f64 usize isize u128 i128;
+    Align64(64) for u16 i16 u32 i32 f32 u64 i64 f64 usize isize u128 i128;
+}
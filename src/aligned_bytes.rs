@@ -0,0 +1,282 @@
+//! Owned byte buffers aligned for a given type.
+//!
+//! The transmutation functions at the [crate root](../index.html) fail with
+//! [`Error::Unaligned`](../error/enum.Error.html#variant.Unaligned) whenever
+//! the input bytes happen not to be suitably aligned. An
+//! [`AlignedBytes<T>`](struct.AlignedBytes.html) always allocates its buffer
+//! with the alignment of `T`, so views into it never hit that error.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use alloc::alloc::{handle_alloc_error, dealloc, realloc, Layout, alloc};
+use core::mem::{align_of, size_of};
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+use core::{fmt, ptr, slice};
+
+
+/// A growable byte buffer whose data is always aligned for reading and writing `T`s.
+///
+/// The length of the buffer is measured in bytes, and does not need to be a
+/// multiple of `size_of::<T>()`; the typed views only cover the whole `T`s.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::AlignedBytes;
+/// let mut buffer = AlignedBytes::<u32>::from_slice(&[0x00, 0x00, 0x00]);
+/// assert_eq!(buffer.as_slice(), &[]);
+///
+/// buffer.push_bytes(&[0x01, 0x02, 0x02, 0x02, 0x02]);
+/// assert_eq!(buffer.len(), 8);
+/// assert_eq!(buffer.as_slice(), &[u32::from_be(0x0000_0001), 0x0202_0202]);
+///
+/// buffer.as_mut_slice()[0] = u32::from_be(0x0A0B_0C0D);
+/// assert_eq!(buffer.as_bytes(), &[0x0A, 0x0B, 0x0C, 0x0D, 0x02, 0x02, 0x02, 0x02]);
+/// ```
+///
+/// Since the buffer hands out `T`s, it is only `Send` or `Sync` if `T` is:
+///
+/// ```compile_fail
+/// # use safe_transmute::AlignedBytes;
+/// # use std::cell::Cell;
+/// fn assert_sync<S: Sync>() {}
+/// assert_sync::<AlignedBytes<Cell<u8>>>();
+/// ```
+pub struct AlignedBytes<T> {
+    ptr: NonNull<u8>,
+    len: usize,
+    capacity: usize,
+    marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send> Send for AlignedBytes<T> {}
+unsafe impl<T: Sync> Sync for AlignedBytes<T> {}
+
+impl<T> AlignedBytes<T> {
+    /// Create a new, empty buffer.
+    ///
+    /// This does not allocate.
+    pub fn new() -> AlignedBytes<T> {
+        AlignedBytes {
+            ptr: unsafe { NonNull::new_unchecked(align_of::<T>() as *mut u8) },
+            len: 0,
+            capacity: 0,
+            marker: PhantomData,
+        }
+    }
+
+    /// Create a new, empty buffer with room for at least `capacity` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::AlignedBytes;
+    /// let buffer = AlignedBytes::<u64>::with_capacity(20);
+    /// assert!(buffer.capacity() >= 20);
+    /// assert!(buffer.is_empty());
+    /// ```
+    pub fn with_capacity(capacity: usize) -> AlignedBytes<T> {
+        let mut buffer = AlignedBytes::new();
+        buffer.reserve(capacity);
+        buffer
+    }
+
+    /// Create a new buffer holding a copy of the given bytes.
+    pub fn from_slice(bytes: &[u8]) -> AlignedBytes<T> {
+        let mut buffer = AlignedBytes::with_capacity(bytes.len());
+        buffer.push_bytes(bytes);
+        buffer
+    }
+
+    /// The amount of bytes in the buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the buffer holds no bytes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The amount of bytes the buffer can hold without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Reserve room for at least `additional` more bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize` or is not a valid allocation size.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required <= self.capacity {
+            return;
+        }
+
+        let capacity = if required > self.capacity * 2 {
+            required
+        } else {
+            self.capacity * 2
+        };
+        let layout = Layout::from_size_align(capacity, align_of::<T>()).expect("capacity overflow");
+
+        let ptr = unsafe {
+            if self.capacity == 0 {
+                alloc(layout)
+            } else {
+                realloc(self.ptr.as_ptr(), self.layout(), capacity)
+            }
+        };
+        self.ptr = match NonNull::new(ptr) {
+            Some(ptr) => ptr,
+            None => handle_alloc_error(layout),
+        };
+        self.capacity = capacity;
+    }
+
+    /// Append the given bytes to the end of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::AlignedBytes;
+    /// let mut buffer = AlignedBytes::<u16>::new();
+    /// buffer.push_bytes(&[0x01, 0x02]);
+    /// buffer.push_bytes(&[0x03]);
+    /// assert_eq!(buffer.as_bytes(), &[0x01, 0x02, 0x03]);
+    /// ```
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+        unsafe {
+            ptr::copy_nonoverlapping(bytes.as_ptr(), self.ptr.as_ptr().add(self.len), bytes.len());
+        }
+        self.len += bytes.len();
+    }
+
+    /// Resize the buffer to hold exactly `new_len` bytes,
+    /// filling any new space with `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::AlignedBytes;
+    /// let mut buffer = AlignedBytes::<u16>::from_slice(&[0x01]);
+    /// buffer.resize(4, 0xFF);
+    /// assert_eq!(buffer.as_bytes(), &[0x01, 0xFF, 0xFF, 0xFF]);
+    ///
+    /// buffer.resize(2, 0x00);
+    /// assert_eq!(buffer.as_bytes(), &[0x01, 0xFF]);
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: u8) {
+        if new_len > self.len {
+            self.reserve(new_len - self.len);
+            unsafe {
+                ptr::write_bytes(self.ptr.as_ptr().add(self.len), value, new_len - self.len);
+            }
+        }
+        self.len = new_len;
+    }
+
+    /// Shorten the buffer to at most `len` bytes, keeping its capacity.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.len = len;
+        }
+    }
+
+    /// Remove all bytes from the buffer, keeping its capacity.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+
+    /// View the buffer's contents as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// View the buffer's contents as mutable bytes.
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+
+    fn layout(&self) -> Layout {
+        unsafe { Layout::from_size_align_unchecked(self.capacity, align_of::<T>()) }
+    }
+
+    fn element_count(&self) -> usize {
+        if size_of::<T>() == 0 {
+            0
+        } else {
+            self.len / size_of::<T>()
+        }
+    }
+}
+
+impl<T: TriviallyTransmutable> AlignedBytes<T> {
+    /// View the whole `T`s in the buffer.
+    ///
+    /// Trailing bytes which do not fill a whole `T` are not included.
+    /// Zero-sized types always produce an empty slice.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.ptr.as_ptr() as *const T, self.element_count()) }
+    }
+
+    /// Mutably view the whole `T`s in the buffer.
+    ///
+    /// Trailing bytes which do not fill a whole `T` are not included.
+    /// Zero-sized types always produce an empty slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr() as *mut T, self.element_count()) }
+    }
+}
+
+impl<T> Drop for AlignedBytes<T> {
+    fn drop(&mut self) {
+        if self.capacity != 0 {
+            unsafe { dealloc(self.ptr.as_ptr(), self.layout()) }
+        }
+    }
+}
+
+impl<T> Default for AlignedBytes<T> {
+    fn default() -> AlignedBytes<T> {
+        AlignedBytes::new()
+    }
+}
+
+impl<T> Clone for AlignedBytes<T> {
+    fn clone(&self) -> AlignedBytes<T> {
+        AlignedBytes::from_slice(self.as_bytes())
+    }
+}
+
+impl<T> AsRef<[u8]> for AlignedBytes<T> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<T> AsMut<[u8]> for AlignedBytes<T> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.as_mut_bytes()
+    }
+}
+
+impl<T> PartialEq for AlignedBytes<T> {
+    fn eq(&self, other: &AlignedBytes<T>) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl<T> Eq for AlignedBytes<T> {}
+
+impl<T> fmt::Debug for AlignedBytes<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AlignedBytes")
+            .field("align", &align_of::<T>())
+            .field("bytes", &self.as_bytes())
+            .finish()
+    }
+}
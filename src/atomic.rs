@@ -0,0 +1,260 @@
+//! Atomic and volatile views of byte buffers.
+//!
+//! Memory shared with other threads or processes (e.g. via `mmap()`) can be
+//! modified at any time, so it cannot be soundly read through a plain `&[u8]`.
+//! Instead, the functions in this module view it as a slice of atomic integers,
+//! or through a [`VolatileSlice`](struct.VolatileSlice.html).
+//!
+//! Transmuting into atomics requires exclusive (`&mut`) access to the bytes,
+//! since any other `&[u8]` aliasing them would otherwise observe writes.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::align::check_alignment_mut;
+use self::super::base::transmuted_len;
+use self::super::guard::{DynGuard, Guard};
+use self::super::Error;
+use core::sync::atomic::{AtomicUsize, AtomicIsize, AtomicU16, AtomicI16, AtomicU32, AtomicI32, AtomicU64, AtomicI64, AtomicU8, AtomicI8, Ordering};
+use core::marker::PhantomData;
+use core::mem::size_of_val;
+use core::{ptr, slice};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+/// An atomic integer type, which can be viewed from and as the bytes of its underlying integer.
+///
+/// # Safety
+///
+/// It is only safe to implement `AtomicInteger` for a type with the same size and
+/// in-memory representation as its `Value`, which is valid for any combination of bytes.
+pub unsafe trait AtomicInteger: Sync {
+    /// The underlying integer type.
+    type Value: TriviallyTransmutable;
+
+    /// Load the current value with `Ordering::Relaxed`.
+    fn load_relaxed(&self) -> Self::Value;
+}
+
+macro_rules! atomic_integers {
+    ($($width:tt: $($atomic:ident($value:ty))*;)*) => {
+        $($(
+            #[cfg(target_has_atomic = $width)]
+            unsafe impl AtomicInteger for $atomic {
+                type Value = $value;
+
+                #[inline]
+                fn load_relaxed(&self) -> $value {
+                    self.load(Ordering::Relaxed)
+                }
+            }
+        )*)*
+    }
+}
+
+atomic_integers! {
+    "8": AtomicU8(u8) AtomicI8(i8);
+    "16": AtomicU16(u16) AtomicI16(i16);
+    "32": AtomicU32(u32) AtomicI32(i32);
+    "64": AtomicU64(u64) AtomicI64(i64);
+    "ptr": AtomicUsize(usize) AtomicIsize(isize);
+}
+
+
+/// View a mutable byte slice as a shared slice of atomic integers.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `A`.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PedanticGuard, AlignOf, Aligned, transmute_atomic_many};
+/// # use std::sync::atomic::{AtomicU32, Ordering};
+/// let mut bytes = Aligned::<AlignOf<AtomicU32>, _>::new([0x00; 8]);
+/// let words = transmute_atomic_many::<AtomicU32, PedanticGuard>(&mut bytes.data).unwrap();
+/// words[1].fetch_add(1, Ordering::SeqCst);
+/// assert_eq!(words[1].load(Ordering::SeqCst), 1);
+/// ```
+pub fn transmute_atomic_many<A: AtomicInteger, G: Guard>(bytes: &mut [u8]) -> Result<&[A], Error<'_, u8, A>> {
+    transmute_atomic_many_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// View a mutable byte slice as a shared slice of atomic integers,
+/// checked against the given guard value.
+///
+/// This is [`transmute_atomic_many()`](fn.transmute_atomic_many.html) with a runtime guard,
+/// see [`DynGuard`](../guard/trait.DynGuard.html).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{AlignOf, Aligned, transmute_atomic_many_with_guard};
+/// # use safe_transmute::guard::CountRange;
+/// # use std::sync::atomic::AtomicU32;
+/// let mut bytes = Aligned::<AlignOf<AtomicU32>, _>::new([0x00; 12]);
+/// assert!(transmute_atomic_many_with_guard::<AtomicU32, _>(&mut bytes.data, &CountRange(1..=2)).is_err());
+/// assert_eq!(transmute_atomic_many_with_guard::<AtomicU32, _>(&mut bytes.data, &CountRange(1..=3)).unwrap().len(), 3);
+/// ```
+pub fn transmute_atomic_many_with_guard<'a, A: AtomicInteger, G: DynGuard>(bytes: &'a mut [u8], guard: &G) -> Result<&'a [A], Error<'a, u8, A>> {
+    let bytes = check_alignment_mut::<_, A>(bytes)?;
+    guard.check_bytes::<A>(bytes)?;
+    unsafe { Ok(slice::from_raw_parts(bytes.as_ptr() as *const A, transmuted_len::<u8, A>(bytes.len()))) }
+}
+
+/// View a mutable slice of atomic integers as a mutable slice of their bytes.
+///
+/// Exclusive access guarantees no concurrent modification,
+/// so this is the same as viewing the underlying integers.
+pub fn transmute_atomic_to_bytes_mut<A: AtomicInteger>(from: &mut [A]) -> &mut [u8] {
+    unsafe { slice::from_raw_parts_mut(from.as_mut_ptr() as *mut u8, size_of_val(from)) }
+}
+
+/// Copy the current values of a slice of atomic integers into the start of a slice of their underlying integers.
+///
+/// Each value is loaded atomically with `Ordering::Relaxed`, but the snapshot as a whole is not:
+/// concurrent modifications may be observed in some elements, but not in others.
+/// This is racy, but well-defined, unlike reading the same memory as a plain `&[u8]`.
+///
+/// Returns the amount of values copied, i.e. the length of the shorter slice.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::snapshot_atomics;
+/// # use std::sync::atomic::AtomicU8;
+/// let shared = [AtomicU8::new(1), AtomicU8::new(2), AtomicU8::new(3)];
+/// let mut bytes = [0u8; 2];
+/// assert_eq!(snapshot_atomics(&shared, &mut bytes), 2);
+/// assert_eq!(bytes, [1, 2]);
+/// ```
+pub fn snapshot_atomics<A: AtomicInteger>(src: &[A], dst: &mut [A::Value]) -> usize {
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = s.load_relaxed();
+    }
+    src.len().min(dst.len())
+}
+
+/// Copy the current values of a slice of atomic integers into a vector of their underlying integers.
+///
+/// See [`snapshot_atomics()`](fn.snapshot_atomics.html) for the consistency guarantees.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::snapshot_atomics_vec;
+/// # use std::sync::atomic::AtomicU16;
+/// let shared = [AtomicU16::new(0x0102), AtomicU16::new(0x0304)];
+/// assert_eq!(snapshot_atomics_vec(&shared), [0x0102, 0x0304]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn snapshot_atomics_vec<A: AtomicInteger>(src: &[A]) -> Vec<A::Value> {
+    src.iter().map(AtomicInteger::load_relaxed).collect()
+}
+
+
+/// A slice of memory which may be modified externally, accessed with volatile reads and writes.
+///
+/// The compiler will neither elide nor reorder these accesses with respect to other volatile accesses,
+/// which makes this suitable e.g. for memory-mapped hardware registers.
+/// Note that volatile accesses are not atomic: they do not synchronise with other threads.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::VolatileSlice;
+/// let mut registers = [0u32; 4];
+/// let volatile = VolatileSlice::from_mut(&mut registers);
+/// volatile.write(2, 0x0102_0304);
+/// assert_eq!(volatile.read(2), 0x0102_0304);
+///
+/// let mut snapshot = [0u32; 4];
+/// volatile.copy_to_slice(&mut snapshot);
+/// assert_eq!(snapshot, [0, 0, 0x0102_0304, 0]);
+/// ```
+#[derive(Debug)]
+pub struct VolatileSlice<'a, T: TriviallyTransmutable> {
+    ptr: *mut T,
+    len: usize,
+    marker: PhantomData<&'a mut [T]>,
+}
+
+impl<'a, T: TriviallyTransmutable> VolatileSlice<'a, T> {
+    /// Create a volatile slice of `len` values at the given pointer.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be non-null, aligned for `T`, and valid for reading and writing
+    /// `len` values of `T` for the lifetime `'a`. No references to the memory may be used
+    /// during that lifetime.
+    pub unsafe fn new(ptr: *mut T, len: usize) -> VolatileSlice<'a, T> {
+        VolatileSlice {
+            ptr,
+            len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Create a volatile slice borrowing the given slice.
+    pub fn from_mut(data: &'a mut [T]) -> VolatileSlice<'a, T> {
+        unsafe { VolatileSlice::new(data.as_mut_ptr(), data.len()) }
+    }
+
+    /// The amount of values in the slice.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the slice holds no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Read the value at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn read(&self, index: usize) -> T {
+        assert!(index < self.len, "index {} out of range for volatile slice of length {}", index, self.len);
+        unsafe { ptr::read_volatile(self.ptr.add(index)) }
+    }
+
+    /// Write the value at the given index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn write(&self, index: usize, value: T) {
+        assert!(index < self.len, "index {} out of range for volatile slice of length {}", index, self.len);
+        unsafe { ptr::write_volatile(self.ptr.add(index), value) }
+    }
+
+    /// Read all values into the given slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of the slices differ.
+    pub fn copy_to_slice(&self, dst: &mut [T]) {
+        assert_eq!(self.len, dst.len(), "destination and volatile slice lengths differ");
+        for (i, d) in dst.iter_mut().enumerate() {
+            *d = unsafe { ptr::read_volatile(self.ptr.add(i)) };
+        }
+    }
+
+    /// Write all values from the given slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of the slices differ.
+    pub fn copy_from_slice(&self, src: &[T]) {
+        assert_eq!(self.len, src.len(), "source and volatile slice lengths differ");
+        for (i, &s) in src.iter().enumerate() {
+            unsafe { ptr::write_volatile(self.ptr.add(i), s) };
+        }
+    }
+}
@@ -4,13 +4,26 @@
 //! recommended unless you *really* know what you are doing.
 
 
-use self::super::guard::{SingleValueGuard, PermissiveGuard, SingleManyGuard, Guard};
+use self::super::guard::{SingleValueGuard, check_many_bytes, PermissiveGuard, SingleManyGuard, DynGuard, Guard};
 use self::super::error::Error;
+use core::marker::PhantomData;
 use core::mem::size_of;
 #[cfg(feature = "alloc")]
 use core::mem::forget;
 #[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use core::ptr;
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Allocator;
+#[cfg(feature = "allocator_api")]
+use core::mem::ManuallyDrop;
 use core::slice;
 
 
@@ -136,8 +149,37 @@ pub unsafe fn from_bytes_pedantic<T: Copy>(bytes: &[u8]) -> Result<T, Error<u8,
 /// # }
 /// ```
 pub unsafe fn transmute_many<T, G: Guard>(bytes: &[u8]) -> Result<&[T], Error<u8, T>> {
-    G::check::<T>(bytes)?;
-    Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, bytes.len() / size_of::<T>()))
+    transmute_many_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// View a byte slice as a slice of an arbitrary type,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many()`](fn.transmute_many.html) with a runtime guard,
+/// see [`DynGuard`](../guard/trait.DynGuard.html).
+///
+/// # Safety
+///
+/// See [`transmute_many()`](fn.transmute_many.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `guard`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::base::transmute_many_with_guard;
+/// # use safe_transmute::guard::ExactCount;
+/// unsafe {
+///     assert_eq!(transmute_many_with_guard::<i8, _>(&[0x00, 0xFF], &ExactCount(2)), Ok(&[0x00, -0x01][..]));
+///     assert!(transmute_many_with_guard::<i8, _>(&[0x00, 0xFF], &ExactCount(3)).is_err());
+/// }
+/// ```
+pub unsafe fn transmute_many_with_guard<'a, T, G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<&'a [T], Error<'a, u8, T>> {
+    check_many_bytes::<T, _>(guard, bytes)?;
+    Ok(slice::from_raw_parts(bytes.as_ptr() as *const T, transmuted_len::<u8, T>(bytes.len())))
 }
 
 /// View a mutable byte slice as a slice of an arbitrary type.
@@ -181,8 +223,37 @@ pub unsafe fn transmute_many<T, G: Guard>(bytes: &[u8]) -> Result<&[T], Error<u8
 /// # }
 /// ```
 pub unsafe fn transmute_many_mut<T, G: Guard>(bytes: &mut [u8]) -> Result<&mut [T], Error<u8, T>> {
-    G::check::<T>(bytes)?;
-    Ok(slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, bytes.len() / size_of::<T>()))
+    transmute_many_mut_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// View a mutable byte slice as a slice of an arbitrary type,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many_mut()`](fn.transmute_many_mut.html) with a runtime guard,
+/// see [`DynGuard`](../guard/trait.DynGuard.html).
+///
+/// # Safety
+///
+/// See [`transmute_many_mut()`](fn.transmute_many_mut.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `guard`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::base::transmute_many_mut_with_guard;
+/// # use safe_transmute::guard::MaxBytes;
+/// unsafe {
+///     assert_eq!(transmute_many_mut_with_guard::<i8, _>(&mut [0x00, 0xFF], &MaxBytes(2)), Ok(&mut [0x00, -0x01][..]));
+///     assert!(transmute_many_mut_with_guard::<i8, _>(&mut [0x00, 0xFF], &MaxBytes(1)).is_err());
+/// }
+/// ```
+pub unsafe fn transmute_many_mut_with_guard<'a, T, G: DynGuard>(bytes: &'a mut [u8], guard: &G) -> Result<&'a mut [T], Error<'a, u8, T>> {
+    check_many_bytes::<T, _>(guard, bytes)?;
+    Ok(slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut T, transmuted_len::<u8, T>(bytes.len())))
 }
 
 /// View a byte slice as a slice of an arbitrary type.
@@ -192,6 +263,11 @@ pub unsafe fn transmute_many_mut<T, G: Guard>(bytes: &mut [u8]) -> Result<&mut [
 /// this function to return a slice directly. It is therefore equivalent to
 /// `transmute_many::<_, PermissiveGuard>(bytes).unwrap()`.
 ///
+/// # Panics
+///
+/// This function panics if `T` is zero-sized, see
+/// [the crate documentation](../index.html#zero-sized-types).
+///
 /// # Safety
 ///
 /// - This function does not perform memory alignment checks. The beginning of
@@ -258,8 +334,171 @@ pub unsafe fn transmute_many_permissive<T>(bytes: &[u8]) -> &[T] {
 #[cfg(feature = "alloc")]
 pub unsafe fn transmute_vec<S, T>(mut vec: Vec<S>) -> Vec<T> {
     let ptr = vec.as_mut_ptr();
-    let capacity = vec.capacity() * size_of::<S>() / size_of::<T>();
-    let len = vec.len() * size_of::<S>() / size_of::<T>();
+    let capacity = transmuted_len::<S, T>(vec.capacity());
+    let len = transmuted_len::<S, T>(vec.len());
     forget(vec);
     Vec::from_raw_parts(ptr as *mut T, len, capacity)
 }
+
+/// Transform a boxed slice into a boxed slice of another element type.
+///
+/// The box's allocated byte buffer (if already allocated) will be reused.
+///
+/// # Safety
+///
+/// Boxed slice transmutations are **exceptionally** dangerous because of
+/// the constraints imposed by
+/// [`Box::from_raw()`](https://doc.rust-lang.org/std/boxed/struct.Box.html#method.from_raw).
+///
+/// Unless *all* of the following requirements are fulfilled, this operation
+/// may result in undefined behavior:
+///
+/// - The target type `T` must have the same size and minimum alignment as the
+///   type `S`.
+/// - The boxed slice's data needs to correspond to a valid contiguous sequence
+///   of `T` values. Types `T` with a `Drop` implementation are unlikely to be
+///   safe in this regard.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::base::transmute_box_slice;
+/// unsafe {
+///     assert_eq!(
+///         transmute_box_slice::<u8, i8>(vec![0x00, 0x01, 0x00, 0x02].into_boxed_slice()),
+///         vec![0x00i8, 0x01i8, 0x00i8, 0x02i8].into_boxed_slice()
+///     );
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub unsafe fn transmute_box_slice<S, T>(boxed: Box<[S]>) -> Box<[T]> {
+    let len = transmuted_len::<S, T>(boxed.len());
+    let ptr = Box::into_raw(boxed) as *mut S;
+    Box::from_raw(ptr::slice_from_raw_parts_mut(ptr as *mut T, len))
+}
+
+/// Transform a reference-counted slice into a reference-counted slice of another element type.
+///
+/// The shared allocation will be reused, so all other owners of the source
+/// reference-counted slice keep seeing the same data.
+///
+/// # Safety
+///
+/// Reference-counted slice transmutations are **exceptionally** dangerous because of
+/// the constraints imposed by
+/// [`Rc::from_raw()`](https://doc.rust-lang.org/std/rc/struct.Rc.html#method.from_raw).
+///
+/// Unless *all* of the following requirements are fulfilled, this operation
+/// may result in undefined behavior:
+///
+/// - The target type `T` must have the same size and minimum alignment as the
+///   type `S`.
+/// - The slice's data needs to correspond to a valid contiguous sequence of
+///   `T` values. Types `T` with a `Drop` implementation are unlikely to be
+///   safe in this regard.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::base::transmute_rc_slice;
+/// # use std::rc::Rc;
+/// unsafe {
+///     assert_eq!(
+///         transmute_rc_slice::<u8, i8>(Rc::from(&[0x00, 0x01, 0x00, 0x02][..])),
+///         Rc::from(&[0x00i8, 0x01i8, 0x00i8, 0x02i8][..])
+///     );
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub unsafe fn transmute_rc_slice<S, T>(rc: Rc<[S]>) -> Rc<[T]> {
+    let len = transmuted_len::<S, T>(rc.len());
+    let ptr = Rc::into_raw(rc) as *const S;
+    Rc::from_raw(ptr::slice_from_raw_parts(ptr as *const T, len))
+}
+
+/// Transform an atomically reference-counted slice into an atomically reference-counted slice of another element type.
+///
+/// The shared allocation will be reused, so all other owners of the source
+/// atomically reference-counted slice keep seeing the same data.
+///
+/// # Safety
+///
+/// Atomically reference-counted slice transmutations are **exceptionally** dangerous because of
+/// the constraints imposed by
+/// [`Arc::from_raw()`](https://doc.rust-lang.org/std/sync/struct.Arc.html#method.from_raw).
+///
+/// Unless *all* of the following requirements are fulfilled, this operation
+/// may result in undefined behavior:
+///
+/// - The target type `T` must have the same size and minimum alignment as the
+///   type `S`.
+/// - The slice's data needs to correspond to a valid contiguous sequence of
+///   `T` values. Types `T` with a `Drop` implementation are unlikely to be
+///   safe in this regard.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::base::transmute_arc_slice;
+/// # use std::sync::Arc;
+/// unsafe {
+///     assert_eq!(
+///         transmute_arc_slice::<u8, i8>(Arc::from(&[0x00, 0x01, 0x00, 0x02][..])),
+///         Arc::from(&[0x00i8, 0x01i8, 0x00i8, 0x02i8][..])
+///     );
+/// }
+/// ```
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub unsafe fn transmute_arc_slice<S, T>(arc: Arc<[S]>) -> Arc<[T]> {
+    let len = transmuted_len::<S, T>(arc.len());
+    let ptr = Arc::into_raw(arc) as *const S;
+    Arc::from_raw(ptr::slice_from_raw_parts(ptr as *const T, len))
+}
+
+/// Transform a vector in a custom allocator into a vector of another element type,
+/// in the same allocator.
+///
+/// The vector's allocated byte buffer (if already allocated) will be reused.
+///
+/// Requires the `allocator_api` feature, and thus a nightly rustc.
+///
+/// # Safety
+///
+/// The same requirements as for [`transmute_vec()`](fn.transmute_vec.html) apply.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(allocator_api)]
+/// # use safe_transmute::base::transmute_vec_in;
+/// # use std::alloc::Global;
+/// let mut bytes = Vec::new_in(Global);
+/// bytes.extend_from_slice(&[0x00u8, 0x01, 0x00, 0x02]);
+///
+/// unsafe {
+///     assert_eq!(transmute_vec_in::<u8, i8, _>(bytes), [0x00i8, 0x01i8, 0x00i8, 0x02i8]);
+/// }
+/// ```
+#[cfg(feature = "allocator_api")]
+pub unsafe fn transmute_vec_in<S, T, A: Allocator>(vec: Vec<S, A>) -> Vec<T, A> {
+    let mut vec = ManuallyDrop::new(vec);
+    let ptr = vec.as_mut_ptr();
+    let capacity = transmuted_len::<S, T>(vec.capacity());
+    let len = transmuted_len::<S, T>(vec.len());
+    let alloc = ptr::read(vec.allocator());
+    Vec::from_raw_parts_in(ptr as *mut T, len, capacity, alloc)
+}
+
+
+/// The amount of `T` values which fit into the memory of `count` values of `S`, rounded down.
+///
+/// A zero-sized `T` only fits into the memory of a zero-sized `S`, once per value.
+/// Any amount of memory holds no zero-sized values otherwise; transmutations
+/// of byte data reject zero-sized targets before getting here.
+pub(crate) fn transmuted_len<S, T>(count: usize) -> usize {
+    if size_of::<T>() == 0 {
+        if size_of::<S>() == 0 { count } else { 0 }
+    } else {
+        count * size_of::<S>() / size_of::<T>()
+    }
+}
@@ -21,6 +21,10 @@ use core::mem::transmute;
 use self::super::Error;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "allocator_api")]
+use self::super::base::transmute_vec_in;
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Allocator;
 
 
 /// Makes sure that the bytes represent a sequence of valid boolean values.
@@ -157,3 +161,60 @@ pub fn transmute_bool_vec_pedantic(bytes: Vec<u8>) -> Result<Vec<bool>, Error<'s
     // so the conversion is safe.
     unsafe { Ok(transmute_vec::<u8, bool>(bytes)) }
 }
+
+/// Transform a byte vector in a custom allocator into a vector of bool, in the same allocator.
+///
+/// The vector's allocated byte buffer will be reused when possible.
+///
+/// Requires the `allocator_api` feature, and thus a nightly rustc.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(allocator_api)]
+/// # use safe_transmute::transmute_bool_vec_permissive_in;
+/// # use std::alloc::Global;
+/// let mut bytes = Vec::new_in(Global);
+/// bytes.extend_from_slice(&[0x00, 0x01, 0x00, 0x01]);
+/// assert_eq!(transmute_bool_vec_permissive_in(bytes).unwrap(),
+///            [false, true, false, true]);
+/// assert_eq!(transmute_bool_vec_permissive_in(Vec::new_in(Global)).unwrap(), []);
+/// ```
+#[cfg(feature = "allocator_api")]
+pub fn transmute_bool_vec_permissive_in<A: Allocator>(bytes: Vec<u8, A>) -> Result<Vec<bool, A>, Error<'static, u8, bool>> {
+    check_bool(&bytes)?;
+    PermissiveGuard::check::<u8>(&bytes)?;
+    // Alignment guarantees are ensured, and all values have been checked,
+    // so the conversion is safe.
+    unsafe { Ok(transmute_vec_in::<u8, bool, A>(bytes)) }
+}
+
+/// Transform a byte vector in a custom allocator into a vector of bool, in the same allocator.
+///
+/// The vector's allocated byte buffer will be reused when possible, and
+/// should not be empty.
+///
+/// Requires the `allocator_api` feature, and thus a nightly rustc.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(allocator_api)]
+/// # use safe_transmute::transmute_bool_vec_pedantic_in;
+/// # use std::alloc::Global;
+/// let mut bytes = Vec::new_in(Global);
+/// bytes.extend_from_slice(&[0x00, 0x01, 0x00, 0x01]);
+/// assert_eq!(transmute_bool_vec_pedantic_in(bytes).unwrap(),
+///            [false, true, false, true]);
+///
+/// assert!(transmute_bool_vec_pedantic_in(Vec::new_in(Global)).is_err());
+/// ```
+#[cfg(feature = "allocator_api")]
+pub fn transmute_bool_vec_pedantic_in<A: Allocator>(bytes: Vec<u8, A>) -> Result<Vec<bool, A>, Error<'static, u8, bool>> {
+    check_bool(&bytes)?;
+    PedanticGuard::check::<u8>(&bytes)?;
+
+    // alignment guarantees are ensured, and all values have been checked,
+    // so the conversion is safe.
+    unsafe { Ok(transmute_vec_in::<u8, bool, A>(bytes)) }
+}
@@ -0,0 +1,271 @@
+//! A builder combining the guard, alignment policy and validation of a transmutation.
+//!
+//! Instead of picking one of the many `transmute_many*` functions,
+//! each choice can be made separately on a [`Transmute`](struct.Transmute.html):
+//!
+//! ```
+//! # use safe_transmute::{PermissiveGuard, AlignOf, Aligned, Transmute};
+//! # use safe_transmute::policy::SkipPrefix;
+//! let bytes = Aligned::<AlignOf<u32>, _>::new([0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02]);
+//!
+//! let words = Transmute::<u32>::from(&bytes.data[1..])
+//!     .guard::<PermissiveGuard>()
+//!     .on_unaligned(SkipPrefix)
+//!     .validate(|&w| u32::from_be(w) < 0x100)
+//!     .many()
+//!     .unwrap();
+//! assert_eq!(words, [u32::from_be(1), u32::from_be(2)]);
+//! ```
+//!
+//! Each step only changes the type of the builder, so the result is as cheap as calling
+//! the corresponding function directly.
+
+
+use self::super::full::transmute_many_with_policy_with_guard;
+use self::super::policy::{AlignmentPolicy, Strict};
+use self::super::trivial::TriviallyTransmutable;
+use self::super::guard::{PedanticGuard, DynGuard, Guard};
+use core::marker::PhantomData;
+use self::super::Error;
+use core::fmt;
+
+
+/// A check on the values produced by a transmutation into the output `O` of an alignment policy.
+///
+/// Implemented for [`NoValidation`](struct.NoValidation.html) with any output,
+/// and for any predicate closure `Fn(&T) -> bool` with the outputs of the crate's own alignment policies.
+pub trait Validator<T: TriviallyTransmutable, O> {
+    /// Whether all values in the given output are valid.
+    fn check(&self, output: &O) -> bool;
+}
+
+/// Accept all values without inspecting them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NoValidation;
+
+impl<T: TriviallyTransmutable, O> Validator<T, O> for NoValidation {
+    #[inline(always)]
+    fn check(&self, _: &O) -> bool {
+        true
+    }
+}
+
+impl<T: TriviallyTransmutable, O: sealed::Values<T>, F: Fn(&T) -> bool> Validator<T, O> for F {
+    fn check(&self, output: &O) -> bool {
+        output.all_values(self)
+    }
+}
+
+
+mod sealed {
+    use self::super::super::trivial::TriviallyTransmutable;
+    use self::super::super::policy::UnalignedIter;
+    #[cfg(feature = "alloc")]
+    use alloc::borrow::Cow;
+
+
+    /// The outputs of alignment policies whose values can be validated one by one.
+    pub trait Values<T> {
+        /// Whether all values satisfy the given predicate.
+        fn all_values<F: FnMut(&T) -> bool>(&self, f: F) -> bool;
+    }
+
+    impl<T: TriviallyTransmutable> Values<T> for &[T] {
+        fn all_values<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+            self.iter().all(f)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<'a, T: TriviallyTransmutable> Values<T> for Cow<'a, [T]> {
+        fn all_values<F: FnMut(&T) -> bool>(&self, f: F) -> bool {
+            self.iter().all(f)
+        }
+    }
+
+    impl<'a, T: TriviallyTransmutable> Values<T> for UnalignedIter<'a, T> {
+        fn all_values<F: FnMut(&T) -> bool>(&self, mut f: F) -> bool {
+            self.clone().all(|value| f(&value))
+        }
+    }
+}
+
+
+/// A transmutation of a byte slice into values of type `T`.
+///
+/// Built from the byte slice with `From`, starting off with the [`PedanticGuard`](../guard/struct.PedanticGuard.html),
+/// the [`Strict`](../policy/struct.Strict.html) alignment policy and no validation,
+/// which [`many()`](#method.many) turns into the same as [`transmute_many_pedantic()`](../fn.transmute_many_pedantic.html).
+///
+/// The guard `G` is held as a [`DynGuard`](../guard/trait.DynGuard.html) value,
+/// `PhantomData<G>` for a guard type `G` set with [`guard()`](#method.guard).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::Transmute;
+/// # use safe_transmute::policy::Unaligned;
+/// let mut words = Transmute::<u16>::from(&[0xFF, 0x00, 0x01, 0x12, 0x24][1..])
+///     .on_unaligned(Unaligned)
+///     .many()
+///     .unwrap();
+/// assert_eq!(words.next(), Some(u16::from_be(0x0001)));
+/// assert_eq!(words.next(), Some(u16::from_be(0x1224)));
+/// assert_eq!(words.next(), None);
+/// ```
+pub struct Transmute<'a, T: TriviallyTransmutable, G = PhantomData<PedanticGuard>, P = Strict, V = NoValidation> {
+    bytes: &'a [u8],
+    guard: G,
+    validator: V,
+    marker: PhantomData<(T, P)>,
+}
+
+// Implemented by hand, since `T` and `P` are only markers, and need not be `Clone` or `Debug` themselves
+impl<'a, T: TriviallyTransmutable, G: Clone, P, V: Clone> Clone for Transmute<'a, T, G, P, V> {
+    fn clone(&self) -> Transmute<'a, T, G, P, V> {
+        Transmute {
+            bytes: self.bytes,
+            guard: self.guard.clone(),
+            validator: self.validator.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: TriviallyTransmutable, G: Copy, P, V: Copy> Copy for Transmute<'a, T, G, P, V> {}
+
+impl<'a, T: TriviallyTransmutable, G: fmt::Debug, P, V: fmt::Debug> fmt::Debug for Transmute<'a, T, G, P, V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Transmute")
+            .field("bytes", &self.bytes)
+            .field("guard", &self.guard)
+            .field("validator", &self.validator)
+            .finish()
+    }
+}
+
+impl<'a, T: TriviallyTransmutable> From<&'a [u8]> for Transmute<'a, T> {
+    fn from(bytes: &'a [u8]) -> Transmute<'a, T> {
+        Transmute {
+            bytes,
+            guard: PhantomData,
+            validator: NoValidation,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: TriviallyTransmutable + 'a, G, P, V> Transmute<'a, T, G, P, V> {
+    /// Check the bytes against the guard `G2` instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{SingleValueGuard, Transmute};
+    /// assert!(Transmute::<u8>::from(&[0x00][..]).guard::<SingleValueGuard>().many().is_ok());
+    /// assert!(Transmute::<u8>::from(&[0x00; 2][..]).guard::<SingleValueGuard>().many().is_err());
+    /// ```
+    pub fn guard<G2: Guard>(self) -> Transmute<'a, T, PhantomData<G2>, P, V> {
+        self.with_guard(PhantomData)
+    }
+
+    /// Check the bytes against the given guard value instead.
+    ///
+    /// This is [`guard()`](#method.guard) with a runtime guard,
+    /// see [`DynGuard`](../guard/trait.DynGuard.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::Transmute;
+    /// # use safe_transmute::policy::Unaligned;
+    /// # use safe_transmute::guard::ExactCount;
+    /// let words = Transmute::<u16>::from(&[0x00, 0x01, 0x12, 0x24][..]).with_guard(ExactCount(2)).on_unaligned(Unaligned).many().unwrap();
+    /// assert_eq!(words.len(), 2);
+    /// ```
+    pub fn with_guard<G2: DynGuard>(self, guard: G2) -> Transmute<'a, T, G2, P, V> {
+        Transmute {
+            bytes: self.bytes,
+            guard,
+            validator: self.validator,
+            marker: PhantomData,
+        }
+    }
+
+    /// Handle data not aligned for `T` with the alignment policy `P2` instead,
+    /// see the [`policy`](../policy/index.html) module.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::Transmute;
+    /// # use safe_transmute::policy::Unaligned;
+    /// let words = Transmute::<u16>::from(&[0x00; 4][..]).on_unaligned(Unaligned).many().unwrap();
+    /// assert_eq!(words.len(), 2);
+    /// ```
+    pub fn on_unaligned<P2: AlignmentPolicy<'a, T>>(self, _policy: P2) -> Transmute<'a, T, G, P2, V> {
+        Transmute {
+            bytes: self.bytes,
+            guard: self.guard,
+            validator: self.validator,
+            marker: PhantomData,
+        }
+    }
+
+    /// Check every resulting value with the given predicate, replacing the previous one.
+    ///
+    /// A value failing validation makes [`many()`](#method.many) return `Error::InvalidValue`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{Transmute, Error};
+    /// let even = Transmute::<u8>::from(&[0x02, 0x04, 0x05][..]).validate(|b| b % 2 == 0);
+    /// assert_eq!(even.many(), Err(Error::InvalidValue));
+    /// ```
+    pub fn validate<F: Fn(&T) -> bool>(self, validator: F) -> Transmute<'a, T, G, P, F> {
+        Transmute {
+            bytes: self.bytes,
+            guard: self.guard,
+            validator,
+            marker: PhantomData,
+        }
+    }
+
+    /// The bytes to be transmuted.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a, T: TriviallyTransmutable + 'a, G: DynGuard, P: AlignmentPolicy<'a, T>, V: Validator<T, P::Output>> Transmute<'a, T, G, P, V> {
+    /// Perform the transmutation into a sequence of values.
+    ///
+    /// The output depends on the alignment policy `P`, as with
+    /// [`transmute_many_with_policy_with_guard()`](../fn.transmute_many_with_policy_with_guard.html),
+    /// which is then followed by validation.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in one of the following situations:
+    ///
+    /// - The data does not comply with the policies of the guard `G`.
+    /// - The data does not have a memory alignment compatible with `T`,
+    ///   and the policy `P` does not handle it.
+    /// - A value does not pass validation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{PermissiveGuard, Transmute};
+    /// assert_eq!(Transmute::<u8>::from(&[0x01, 0x02][..]).guard::<PermissiveGuard>().many(), Ok(&[0x01, 0x02][..]));
+    /// ```
+    pub fn many(self) -> Result<P::Output, Error<'a, u8, T>> {
+        let output = transmute_many_with_policy_with_guard::<T, G, P>(self.bytes, &self.guard)?;
+        if self.validator.check(&output) {
+            Ok(output)
+        } else {
+            Err(Error::InvalidValue)
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Transmutation of shared mutable memory, via `Cell`s.
+//!
+//! Several typed views of the same byte buffer can be held at once through these
+//! functions, and read from or written to without `unsafe` or `&mut` exclusivity,
+//! so long as they stay on a single thread.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::align::check_alignment;
+use self::super::guard::{check_many_bytes, DynGuard, Guard};
+use self::super::base::transmuted_len;
+use self::super::Error;
+use core::mem::size_of_val;
+use core::marker::PhantomData;
+use core::cell::Cell;
+use core::slice;
+
+
+/// Transmute a shared mutable byte slice into a sequence of shared mutable values of the given type.
+///
+/// This follows the same rules as [`transmute_many()`](../fn.transmute_many.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`. You will
+///   have to make a copy anyway, or modify how the data was originally made.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{AlignOf, Aligned, PedanticGuard, transmute_cell_many, transmute_cells_to_bytes};
+/// # use std::cell::Cell;
+/// let mut buffer = Aligned::<AlignOf<u16>, _>::new([0x00; 4]);
+/// let bytes = Cell::from_mut(&mut buffer.data[..]);
+///
+/// let words = transmute_cell_many::<u16, PedanticGuard>(bytes).unwrap();
+/// let bytes = transmute_cells_to_bytes(words);
+/// words[1].set(u16::from_be(0x0102));
+/// bytes[0].set(0xFF);
+///
+/// assert_eq!(words[0].get(), u16::from_be(0xFF00));
+/// assert_eq!(bytes[3].get(), 0x02);
+/// ```
+pub fn transmute_cell_many<T: TriviallyTransmutable, G: Guard>(bytes: &Cell<[u8]>) -> Result<&[Cell<T>], Error<'_, Cell<u8>, T>> {
+    transmute_cell_many_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a shared mutable byte slice into a sequence of shared mutable values of the given type,
+/// checked against the given guard value.
+///
+/// This is [`transmute_cell_many()`](fn.transmute_cell_many.html) with a runtime guard,
+/// see [`DynGuard`](../guard/trait.DynGuard.html).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{AlignOf, Aligned, transmute_cell_many_with_guard};
+/// # use safe_transmute::guard::ExactCount;
+/// # use std::cell::Cell;
+/// let mut buffer = Aligned::<AlignOf<u16>, _>::new([0x00; 4]);
+/// let bytes = Cell::from_mut(&mut buffer.data[..]);
+///
+/// assert!(transmute_cell_many_with_guard::<u16, _>(bytes, &ExactCount(3)).is_err());
+/// assert_eq!(transmute_cell_many_with_guard::<u16, _>(bytes, &ExactCount(2)).unwrap().len(), 2);
+/// ```
+pub fn transmute_cell_many_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a Cell<[u8]>, guard: &G)
+                                                                                  -> Result<&'a [Cell<T>], Error<'a, Cell<u8>, T>> {
+    transmute_byte_cells_many_with_guard::<T, G>(bytes.as_slice_of_cells(), guard)
+}
+
+/// Transmute a slice of shared mutable bytes into a sequence of shared mutable values of the given type.
+///
+/// This follows the same rules as [`transmute_many()`](../fn.transmute_many.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`. You will
+///   have to make a copy anyway, or modify how the data was originally made.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{AlignOf, Aligned, SingleManyGuard, transmute_byte_cells_many};
+/// # use std::cell::Cell;
+/// let mut buffer = Aligned::<AlignOf<u32>, _>::new([0x00; 5]);
+/// let bytes = Cell::from_mut(&mut buffer.data[..]).as_slice_of_cells();
+///
+/// let words = transmute_byte_cells_many::<u32, SingleManyGuard>(bytes).unwrap();
+/// assert_eq!(words.len(), 1);
+/// words[0].set(u32::from_be(0x0102_0304));
+/// assert_eq!(bytes[3].get(), 0x04);
+/// ```
+pub fn transmute_byte_cells_many<T: TriviallyTransmutable, G: Guard>(bytes: &[Cell<u8>]) -> Result<&[Cell<T>], Error<'_, Cell<u8>, T>> {
+    transmute_byte_cells_many_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a slice of shared mutable bytes into a sequence of shared mutable values of the given type,
+/// checked against the given guard value.
+///
+/// This is [`transmute_byte_cells_many()`](fn.transmute_byte_cells_many.html) with a runtime guard,
+/// see [`DynGuard`](../guard/trait.DynGuard.html).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{AlignOf, Aligned, transmute_byte_cells_many_with_guard};
+/// # use safe_transmute::guard::MaxBytes;
+/// # use std::cell::Cell;
+/// let mut buffer = Aligned::<AlignOf<u32>, _>::new([0x00; 8]);
+/// let bytes = Cell::from_mut(&mut buffer.data[..]).as_slice_of_cells();
+///
+/// assert!(transmute_byte_cells_many_with_guard::<u32, _>(bytes, &MaxBytes(4)).is_err());
+/// assert!(transmute_byte_cells_many_with_guard::<u32, _>(&bytes[..4], &MaxBytes(4)).is_ok());
+/// ```
+pub fn transmute_byte_cells_many_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a [Cell<u8>], guard: &G)
+                                                                                        -> Result<&'a [Cell<T>], Error<'a, Cell<u8>, T>> {
+    check_alignment::<_, T>(bytes)?;
+    // No `Cell` can be written to while the guard inspects the data, as this is a single thread
+    unsafe {
+        check_many_bytes::<T, _>(guard, slice::from_raw_parts(bytes.as_ptr() as *const u8, bytes.len()))?;
+        Ok(slice::from_raw_parts(bytes.as_ptr() as *const Cell<T>, transmuted_len::<u8, T>(bytes.len())))
+    }
+}
+
+/// View a slice of shared mutable values as a slice of their shared mutable bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_cells_to_bytes;
+/// # use std::cell::Cell;
+/// let words = [Cell::new(0u16), Cell::new(0u16)];
+/// transmute_cells_to_bytes(&words)[2].set(0xFF);
+/// assert_eq!(words[1].get(), u16::from_be(0xFF00));
+/// ```
+pub fn transmute_cells_to_bytes<T: TriviallyTransmutable>(from: &[Cell<T>]) -> &[Cell<u8>] {
+    unsafe { slice::from_raw_parts(from.as_ptr() as *const Cell<u8>, size_of_val(from)) }
+}
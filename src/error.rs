@@ -1,17 +1,27 @@
 //! Detectable and recoverable-from transmutation precondition errors.
 
 
+use self::super::base::transmuted_len;
+use self::super::align::aligned_split;
 use core::fmt;
-#[cfg(feature = "alloc")]
 use core::ptr;
+use core::slice;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::{Allocator, Global};
 use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use core::hash::{Hasher, Hash};
 #[cfg(feature = "std")]
 use std::error::Error as StdError;
-#[cfg(feature = "alloc")]
-use core::mem::{align_of, size_of};
-#[cfg(feature = "alloc")]
+use core::mem::{size_of_val, align_of, size_of};
 use self::super::trivial::TriviallyTransmutable;
 
 
@@ -37,13 +47,32 @@ pub enum Error<'a, S, T> {
     /// Does not exist without the `alloc` feature.
     #[cfg(feature = "alloc")]
     IncompatibleVecTarget(IncompatibleVecTargetError<S, T>),
+    /// The boxed slice's element type does not have the same size and minimum
+    /// alignment as the target type.
+    ///
+    /// Does not exist without the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    IncompatibleBoxTarget(IncompatibleBoxTargetError<S, T>),
+    /// The reference-counted slice's element type does not have the same size
+    /// and minimum alignment as the target type.
+    ///
+    /// Does not exist without the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    IncompatibleRcTarget(IncompatibleRcTargetError<S, T>),
+    /// The atomically reference-counted slice's element type does not have
+    /// the same size and minimum alignment as the target type.
+    ///
+    /// Does not exist without the `alloc` feature, nor on targets without pointer-sized atomics.
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    IncompatibleArcTarget(IncompatibleArcTargetError<S, T>),
     /// The data contains an invalid value for the target type.
     InvalidValue,
 }
 
 impl<'a, S, T> Error<'a, S, T> {
     /// Reattempt the failed transmutation if the failure was caused by either
-    /// an unaligned memory access, or an incompatible vector element target.
+    /// an unaligned memory access, or an incompatible vector, boxed slice, or
+    /// reference-counted slice element target.
     ///
     /// Otherwise return `self`.
     #[cfg(feature = "alloc")]
@@ -53,12 +82,17 @@ impl<'a, S, T> Error<'a, S, T> {
         match self {
             Error::Unaligned(e) => Ok(e.copy()),
             Error::IncompatibleVecTarget(e) => Ok(e.copy()),
+            Error::IncompatibleBoxTarget(e) => Ok(e.copy().into_vec()),
+            Error::IncompatibleRcTarget(e) => Ok(e.copy_to_vec()),
+            #[cfg(target_has_atomic = "ptr")]
+            Error::IncompatibleArcTarget(e) => Ok(e.copy_to_vec()),
             e => Err(e),
         }
     }
 
     /// Reattempt the failed non-trivial transmutation if the failure was caused by either
-    /// an unaligned memory access, or an incompatible vector element target.
+    /// an unaligned memory access, or an incompatible vector, boxed slice, or
+    /// reference-counted slice element target.
     ///
     /// Otherwise return `self`.
     ///
@@ -71,6 +105,10 @@ impl<'a, S, T> Error<'a, S, T> {
         match self {
             Error::Unaligned(e) => Ok(e.copy_unchecked()),
             Error::IncompatibleVecTarget(e) => Ok(e.copy_unchecked()),
+            Error::IncompatibleBoxTarget(e) => Ok(e.copy_unchecked().into_vec()),
+            Error::IncompatibleRcTarget(e) => Ok(e.copy_to_vec_unchecked()),
+            #[cfg(target_has_atomic = "ptr")]
+            Error::IncompatibleArcTarget(e) => Ok(e.copy_to_vec_unchecked()),
             e => Err(e),
         }
     }
@@ -80,10 +118,12 @@ impl<'a, S, T> Error<'a, S, T> {
     /// the error value live longer than the context of transmutation.
     pub fn without_src<'z>(self) -> Error<'z, S, T> {
         match self {
-            Error::Unaligned(UnalignedError { source: _, offset, phantom }) => {
+            Error::Unaligned(UnalignedError { source: _, offset, alignment, modulus, phantom }) => {
                 Error::Unaligned(UnalignedError {
                     source: &[],
                     offset: offset,
+                    alignment,
+                    modulus,
                     phantom: phantom,
                 })
             }
@@ -91,6 +131,12 @@ impl<'a, S, T> Error<'a, S, T> {
             Error::InvalidValue => Error::InvalidValue,
             #[cfg(feature = "alloc")]
             Error::IncompatibleVecTarget(e) => Error::IncompatibleVecTarget(e),
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleBoxTarget(e) => Error::IncompatibleBoxTarget(e),
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleRcTarget(e) => Error::IncompatibleRcTarget(e),
+            #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+            Error::IncompatibleArcTarget(e) => Error::IncompatibleArcTarget(e),
         }
     }
 }
@@ -103,6 +149,12 @@ impl<'a, S, T> fmt::Debug for Error<'a, S, T> {
             Error::InvalidValue => f.write_str("InvalidValue"),
             #[cfg(feature = "alloc")]
             Error::IncompatibleVecTarget(_) => f.write_str("IncompatibleVecTarget"),
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleBoxTarget(_) => f.write_str("IncompatibleBoxTarget"),
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleRcTarget(_) => f.write_str("IncompatibleRcTarget"),
+            #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+            Error::IncompatibleArcTarget(_) => f.write_str("IncompatibleArcTarget"),
         }
     }
 }
@@ -116,6 +168,10 @@ impl<'a, S, T> StdError for Error<'a, S, T> {
             Error::Unaligned(e) => e.description(),
             Error::InvalidValue => "invalid target value",
             Error::IncompatibleVecTarget(e) => e.description(),
+            Error::IncompatibleBoxTarget(e) => e.description(),
+            Error::IncompatibleRcTarget(e) => e.description(),
+            #[cfg(target_has_atomic = "ptr")]
+            Error::IncompatibleArcTarget(e) => e.description(),
         }
     }
 }
@@ -128,6 +184,12 @@ impl<'a, S, T> fmt::Display for Error<'a, S, T> {
             Error::InvalidValue => f.write_str("Invalid target value"),
             #[cfg(feature = "alloc")]
             Error::IncompatibleVecTarget(e) => e.fmt(f),
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleBoxTarget(e) => e.fmt(f),
+            #[cfg(feature = "alloc")]
+            Error::IncompatibleRcTarget(e) => e.fmt(f),
+            #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+            Error::IncompatibleArcTarget(e) => e.fmt(f),
         }
     }
 }
@@ -180,12 +242,14 @@ pub struct GuardError {
 pub enum ErrorReason {
     /// Too few bytes to fill even one instance of a type.
     NotEnoughBytes,
-    /// Too many bytes to fill a type.
-    ///
-    /// Currently unused.
+    /// Too many bytes to fill a type, or the allowed amount of instances of it.
     TooManyBytes,
     /// The byte amount received is not the same as the type's size.
     InexactByteCount,
+    /// The target type is zero-sized, so the byte amount cannot tell how many instances of it there are.
+    ///
+    /// `required` is then always 0.
+    ZeroSizedTarget,
 }
 
 #[cfg(feature = "std")]
@@ -208,10 +272,38 @@ impl ErrorReason {
             ErrorReason::NotEnoughBytes => "Not enough bytes to fill type",
             ErrorReason::TooManyBytes => "Too many bytes for type",
             ErrorReason::InexactByteCount => "Not exactly the amount of bytes for type",
+            ErrorReason::ZeroSizedTarget => "Zero-sized target type",
         }
     }
 }
 
+/// Copy the given data into the start of the given slice, transmuting it in the process.
+///
+/// Returns the amount of `T` values written.
+///
+/// # Errors
+///
+/// An `ErrorReason::NotEnoughBytes` error is returned if `dst` is too short to hold all of the data.
+/// The error's `required` and `actual` fields describe the destination size in bytes.
+///
+/// # Safety
+///
+/// The byte data in the slice needs to correspond to a valid contiguous
+/// sequence of `T` values.
+pub(crate) unsafe fn copy_to_slice_unchecked<S, T>(data: &[S], dst: &mut [T]) -> Result<usize, GuardError> {
+    let len = transmuted_len::<S, T>(data.len());
+    if dst.len() < len {
+        return Err(GuardError {
+            required: len * size_of::<T>(),
+            actual: size_of_val(dst),
+            reason: ErrorReason::NotEnoughBytes,
+        });
+    }
+
+    ptr::copy_nonoverlapping(data.as_ptr() as *const u8, dst.as_mut_ptr() as *mut u8, len * size_of::<T>());
+    Ok(len)
+}
+
 /// Create a copy of the given data, transmuted into a vector.
 ///
 /// # Safety
@@ -220,7 +312,7 @@ impl ErrorReason {
 /// sequence of `T` values.
 #[cfg(feature = "alloc")]
 unsafe fn copy_to_vec_unchecked<S, T>(data: &[S]) -> Vec<T> {
-    let len = data.len() * size_of::<S>() / size_of::<T>();
+    let len = transmuted_len::<S, T>(data.len());
 
     let mut out = Vec::with_capacity(len);
     ptr::copy_nonoverlapping(data.as_ptr() as *const u8, out.as_mut_ptr() as *mut u8, len * size_of::<T>());
@@ -229,16 +321,63 @@ unsafe fn copy_to_vec_unchecked<S, T>(data: &[S]) -> Vec<T> {
     out
 }
 
+/// Create a copy of the given vector's data, transmuted into a vector in the same allocator.
+///
+/// # Safety
+///
+/// The byte data in the vector needs to correspond to a valid contiguous
+/// sequence of `T` values.
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+unsafe fn copy_vec_unchecked<S, T>(data: &[S]) -> Vec<T> {
+    copy_to_vec_unchecked::<S, T>(data)
+}
+
+/// Create a copy of the given vector's data, transmuted into a vector in the same allocator.
+///
+/// # Safety
+///
+/// The byte data in the vector needs to correspond to a valid contiguous
+/// sequence of `T` values.
+#[cfg(feature = "allocator_api")]
+unsafe fn copy_vec_unchecked<S, T, A: Allocator + Clone>(data: &Vec<S, A>) -> Vec<T, A> {
+    let len = transmuted_len::<S, T>(data.len());
+
+    let mut out = Vec::with_capacity_in(len, data.allocator().clone());
+    ptr::copy_nonoverlapping(data.as_ptr() as *const u8, out.as_mut_ptr() as *mut u8, len * size_of::<T>());
+
+    out.set_len(len);
+    out
+}
+
 /// Unaligned memory access error.
 ///
 /// Returned when the given data slice is not properly aligned for the target
 /// type. It would have been properly aligned if `offset` bytes were shifted
 /// (discarded) from the front of the slice.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{AlignOf, Aligned, Error, transmute_many_permissive};
+/// let bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 12]);
+/// match transmute_many_permissive::<[u32; 2]>(&bytes.data[1..]) {
+///     Err(Error::Unaligned(e)) => {
+///         assert_eq!(e.alignment, 4);
+///         assert_eq!(e.modulus, 1);
+///         assert_eq!(e.offset, 3);
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
 #[derive(Clone, Eq, Hash, PartialEq)]
 pub struct UnalignedError<'a, S, T> {
     /// The required amount of bytes to discard at the front for the attempted
     /// transmutation to be successful.
     pub offset: usize,
+    /// The alignment required for `T`, in bytes.
+    pub alignment: usize,
+    /// The address of the source data, modulo `alignment`.
+    pub modulus: usize,
     /// A slice of the original source data.
     pub source: &'a [S],
 
@@ -246,9 +385,13 @@ pub struct UnalignedError<'a, S, T> {
 }
 
 impl<'a, S, T> UnalignedError<'a, S, T> {
+    /// Create a new error for the given source data,
+    /// which would be aligned for `T` after discarding `offset` bytes.
     pub fn new(offset: usize, source: &'a [S]) -> Self {
         UnalignedError {
             offset: offset,
+            alignment: align_of::<T>(),
+            modulus: source.as_ptr() as usize % align_of::<T>(),
             source: source,
             phantom: PhantomData,
         }
@@ -280,6 +423,100 @@ impl<'a, S, T> UnalignedError<'a, S, T> {
             self.copy_unchecked()
         }
     }
+
+    /// Copy the source data into the start of the given slice, transmuting it in the process.
+    /// As the slice is properly aligned for accessing values of type `T`, this
+    /// operation will not fail due to memory alignment constraints, nor allocate.
+    ///
+    /// Returns the amount of `T` values written.
+    ///
+    /// # Errors
+    ///
+    /// An `ErrorReason::NotEnoughBytes` error is returned if `dst` is too short to hold all of the source data.
+    /// The error's `required` and `actual` fields describe the destination size in bytes.
+    ///
+    /// # Safety
+    ///
+    /// The byte data in the slice needs to correspond to a valid contiguous
+    /// sequence of `T` values.
+    pub unsafe fn copy_into_unchecked(&self, dst: &mut [T]) -> Result<usize, GuardError> {
+        copy_to_slice_unchecked::<S, T>(self.source, dst)
+    }
+
+    /// Copy the source data into the start of the given slice, transmuting it in the process.
+    /// As `T` is trivially transmutable, and the slice is properly aligned for
+    /// accessing values of type `T`, this operation is safe and will not fail
+    /// due to memory alignment constraints, nor allocate.
+    ///
+    /// Returns the amount of `T` values written.
+    ///
+    /// # Errors
+    ///
+    /// An `ErrorReason::NotEnoughBytes` error is returned if `dst` is too short to hold all of the source data.
+    /// The error's `required` and `actual` fields describe the destination size in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{Error, transmute_many_permissive};
+    /// let bytes = &[0x00, 0x01, 0x12, 0x24, 0x00];
+    /// let mut storage = [0u16; 4];
+    ///
+    /// let words = match transmute_many_permissive::<u16>(bytes) {
+    ///     Ok(words) => words,
+    ///     Err(Error::Unaligned(e)) => {
+    ///         let len = e.copy_into(&mut storage)?;
+    ///         &storage[..len]
+    ///     }
+    ///     Err(e) => panic!("Unexpected error: {}", e),
+    /// };
+    /// assert_eq!(words, [u16::from_be(0x0001), u16::from_be(0x1224)]);
+    /// # Ok::<(), safe_transmute::GuardError>(())
+    /// ```
+    pub fn copy_into(&self, dst: &mut [T]) -> Result<usize, GuardError>
+        where T: TriviallyTransmutable
+    {
+        unsafe {
+            // no value checks needed thanks to `TriviallyTransmutable`
+            self.copy_into_unchecked(dst)
+        }
+    }
+}
+
+impl<'a, S: TriviallyTransmutable, T: TriviallyTransmutable> UnalignedError<'a, S, T> {
+    /// Split the source data into a misaligned prefix, the values of type `T` in
+    /// the properly aligned middle, and a suffix too short to fill a value, without copying.
+    ///
+    /// The prefix is as short as possible: for a byte source, shorter than the alignment of `T`
+    /// unless the source itself is. It holds all of the source if none of its elements are aligned for `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{AlignOf, Aligned, Error, transmute_many_permissive};
+    /// let bytes = Aligned::<AlignOf<u16>, _>::new([0xFF, 0x00, 0x01, 0x12, 0x24, 0xEE, 0xDD]);
+    ///
+    /// match transmute_many_permissive::<u16>(&bytes.data[1..]) {
+    ///     Err(Error::Unaligned(e)) => {
+    ///         let (prefix, words, suffix) = e.realign();
+    ///         assert_eq!(prefix, &[0x00]);
+    ///         assert_eq!(words, [u16::from_be(0x0112), u16::from_be(0x24EE)]);
+    ///         assert_eq!(suffix, &[0xDD]);
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn realign(&self) -> (&'a [S], &'a [T], &'a [S]) {
+        let (prefix_len, middle_len) = aligned_split::<_, T>(self.source);
+        let (prefix, rest) = self.source.split_at(prefix_len);
+        let (middle, suffix) = rest.split_at(middle_len);
+        // An empty middle may not be aligned, if the source ends before the first aligned position
+        let values = match transmuted_len::<S, T>(middle_len) {
+            0 => &[],
+            count => unsafe { slice::from_raw_parts(middle.as_ptr() as *const T, count) },
+        };
+        (prefix, values, suffix)
+    }
 }
 
 impl<'a, S, T> fmt::Debug for UnalignedError<'a, S, T> {
@@ -300,6 +537,8 @@ impl<'a, S, T> fmt::Debug for UnalignedError<'a, S, T> {
 
         f.debug_struct("UnalignedError")
             .field("offset", &self.offset)
+            .field("alignment", &self.alignment)
+            .field("modulus", &self.modulus)
             .field("source", &Source { len: self.source.len() })
             .finish()
     }
@@ -314,7 +553,46 @@ impl<'a, S, T> StdError for UnalignedError<'a, S, T> {
 
 impl<'a, S, T> fmt::Display for UnalignedError<'a, S, T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "data is unaligned (off by {} bytes)", self.offset)
+        write!(f, "data is unaligned (address is {} modulo {}, off by {} bytes)", self.modulus, self.alignment, self.offset)
+    }
+}
+
+
+/// Implement `Debug`, `Display` and `Error` for the incompatible target error type `$name`,
+/// generic over the allocator `A`, if one is specified.
+///
+/// `$kind` names the transmutation in `Display`.
+#[cfg(feature = "alloc")]
+macro_rules! impl_incompatible_target_error_fmt {
+    ($name:ident, $kind:expr $(, $alloc:ident)*) => {
+        impl<S, T $(, $alloc: Allocator)*> fmt::Debug for $name<S, T $(, $alloc)*> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("size_of<S>", &size_of::<S>())
+                    .field("align_of<S>", &align_of::<S>())
+                    .field("size_of<T>", &size_of::<T>())
+                    .field("align_of<T>", &align_of::<T>())
+                    .finish()
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl<S, T $(, $alloc: Allocator)*> StdError for $name<S, T $(, $alloc)*> {
+            fn description(&self) -> &str {
+                "incompatible target type"
+            }
+        }
+
+        impl<S, T $(, $alloc: Allocator)*> fmt::Display for $name<S, T $(, $alloc)*> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f,
+                       concat!("incompatible target type (size: {}, align: {}) for ", $kind, "transmutation from source (size: {}, align: {})"),
+                       size_of::<T>(),
+                       align_of::<T>(),
+                       size_of::<S>(),
+                       align_of::<S>())
+            }
+        }
     }
 }
 
@@ -328,49 +606,86 @@ impl<'a, S, T> fmt::Display for UnalignedError<'a, S, T> {
 /// - `std::mem::align_of::<S>() != std::mem::align_of::<T>()`
 /// - `std::mem::size_of::<S>() != std::mem::size_of::<T>()`
 #[cfg(feature = "alloc")]
-#[derive(Clone, Eq, Hash, PartialEq)]
-pub struct IncompatibleVecTargetError<S, T> {
+#[derive(Clone)]
+pub struct IncompatibleVecTargetError<S, T, #[cfg(feature = "allocator_api")] A: Allocator = Global> {
     /// The original vector.
+    #[cfg(not(feature = "allocator_api"))]
     pub vec: Vec<S>,
+    /// The original vector, in its original allocator.
+    #[cfg(feature = "allocator_api")]
+    pub vec: Vec<S, A>,
     /// The target element type
     target: PhantomData<T>,
 }
 
+/// Implement `IncompatibleVecTargetError`'s methods and traits,
+/// generic over the vector's allocator `A`, if one is specified.
 #[cfg(feature = "alloc")]
-impl<S, T> IncompatibleVecTargetError<S, T> {
-    /// Create an error with the given vector.
-    pub fn new(vec: Vec<S>) -> Self {
-        IncompatibleVecTargetError {
-            vec: vec,
-            target: PhantomData,
+macro_rules! impl_incompatible_vec_target_error {
+    ($($alloc:ident)*) => {
+        impl<S, T $(, $alloc: Allocator)*> IncompatibleVecTargetError<S, T $(, $alloc)*> {
+            /// Create an error with the given vector.
+            pub fn new(vec: Vec<S $(, $alloc)*>) -> Self {
+                IncompatibleVecTargetError {
+                    vec,
+                    target: PhantomData,
+                }
+            }
+
+            /// Create a copy of the data, transmuted into a new vector. As the vector
+            /// will be properly aligned for accessing values of type `T`, this
+            /// operation will not fail due to memory alignment constraints.
+            ///
+            /// The new vector is allocated in the same allocator as the original one.
+            ///
+            /// # Safety
+            ///
+            /// The byte data in the vector needs to correspond to a valid contiguous
+            /// sequence of `T` values.
+            pub unsafe fn copy_unchecked(&self) -> Vec<T $(, $alloc)*>
+                $(where $alloc: Clone)*
+            {
+                copy_vec_unchecked::<S, T $(, $alloc)*>(&self.vec)
+            }
+
+            /// Create a copy of the data, transmuted into a new vector. As `T` is
+            /// trivially transmutable, and the new vector will be properly allocated
+            /// for accessing values of type `T`, this operation is safe and will never fail.
+            ///
+            /// The new vector is allocated in the same allocator as the original one.
+            pub fn copy(&self) -> Vec<T $(, $alloc)*>
+                where T: TriviallyTransmutable $(, $alloc: Clone)*
+            {
+                unsafe {
+                    // no value checks needed thanks to `TriviallyTransmutable`
+                    self.copy_unchecked()
+                }
+            }
         }
-    }
 
-    /// Create a copy of the data, transmuted into a new vector. As the vector
-    /// will be properly aligned for accessing values of type `T`, this
-    /// operation will not fail due to memory alignment constraints.
-    ///
-    /// # Safety
-    ///
-    /// The byte data in the vector needs to correspond to a valid contiguous
-    /// sequence of `T` values.
-    pub unsafe fn copy_unchecked(&self) -> Vec<T> {
-        copy_to_vec_unchecked::<S, T>(&self.vec)
-    }
+        impl<S: PartialEq, T $(, $alloc: Allocator)*> PartialEq for IncompatibleVecTargetError<S, T $(, $alloc)*> {
+            fn eq(&self, other: &Self) -> bool {
+                self.vec == other.vec
+            }
+        }
 
-    /// Create a copy of the data, transmuted into a new vector. As `T` is
-    /// trivially transmutable, and the new vector will be properly allocated
-    /// for accessing values of type `T`, this operation is safe and will never fail.
-    pub fn copy(&self) -> Vec<T>
-        where T: TriviallyTransmutable
-    {
-        unsafe {
-            // no value checks needed thanks to `TriviallyTransmutable`
-            self.copy_unchecked()
+        impl<S: Eq, T $(, $alloc: Allocator)*> Eq for IncompatibleVecTargetError<S, T $(, $alloc)*> {}
+
+        impl<S: Hash, T $(, $alloc: Allocator)*> Hash for IncompatibleVecTargetError<S, T $(, $alloc)*> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.vec.hash(state)
+            }
         }
+
+        impl_incompatible_target_error_fmt!(IncompatibleVecTargetError, "" $(, $alloc)*);
     }
 }
 
+#[cfg(all(feature = "alloc", not(feature = "allocator_api")))]
+impl_incompatible_vec_target_error!();
+#[cfg(feature = "allocator_api")]
+impl_incompatible_vec_target_error!(A);
+
 #[cfg(feature = "alloc")]
 impl<'a, S, T> From<IncompatibleVecTargetError<S, T>> for Error<'a, S, T> {
     fn from(e: IncompatibleVecTargetError<S, T>) -> Self {
@@ -378,33 +693,132 @@ impl<'a, S, T> From<IncompatibleVecTargetError<S, T>> for Error<'a, S, T> {
     }
 }
 
+/// Define an error type for an incompatible element type of the slice pointer `$ptr<[S]>`,
+/// holding the original pointer in the public field `$field`, and its `From` conversion into `Error::$variant`.
+///
+/// `$what` names the slice pointer in documentation, and `$kind` names the transmutation in `Display`.
 #[cfg(feature = "alloc")]
-impl<S, T> fmt::Debug for IncompatibleVecTargetError<S, T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct("IncompatibleVecTargetError")
-            .field("size_of<S>", &size_of::<S>())
-            .field("align_of<S>", &align_of::<S>())
-            .field("size_of<T>", &size_of::<T>())
-            .field("align_of<T>", &align_of::<T>())
-            .finish()
+macro_rules! incompatible_slice_target_error {
+    ($(#[$attr:meta])* pub struct $name:ident { $field:ident: $ptr:ident }, $variant:ident, $what:expr, $kind:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Eq, Hash, PartialEq)]
+        pub struct $name<S, T> {
+            #[doc = concat!("The original ", $what, ".")]
+            pub $field: $ptr<[S]>,
+            /// The target element type
+            target: PhantomData<T>,
+        }
+
+        impl<S, T> $name<S, T> {
+            #[doc = concat!("Create an error with the given ", $what, ".")]
+            pub fn new($field: $ptr<[S]>) -> Self {
+                $name {
+                    $field,
+                    target: PhantomData,
+                }
+            }
+
+            /// Create a copy of the data, transmuted into a new vector. As the
+            /// vector will be properly aligned for accessing values of type `T`, this
+            /// operation will not fail due to memory alignment constraints.
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("The byte data in the ", $what, " needs to correspond to a valid")]
+            /// contiguous sequence of `T` values.
+            pub unsafe fn copy_to_vec_unchecked(&self) -> Vec<T> {
+                copy_to_vec_unchecked::<S, T>(&self.$field)
+            }
+
+            /// Create a copy of the data, transmuted into a new vector. As `T` is
+            /// trivially transmutable, and the vector will be properly allocated
+            /// for accessing values of type `T`, this operation is safe and will never fail.
+            pub fn copy_to_vec(&self) -> Vec<T>
+                where T: TriviallyTransmutable
+            {
+                unsafe {
+                    // no value checks needed thanks to `TriviallyTransmutable`
+                    self.copy_to_vec_unchecked()
+                }
+            }
+
+            #[doc = concat!("Create a copy of the data, transmuted into a new ", $what, ". As the")]
+            /// copy will be properly aligned for accessing values of type `T`, this
+            /// operation will not fail due to memory alignment constraints.
+            ///
+            #[doc = concat!("The original ", $what, " is left untouched.")]
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("The byte data in the ", $what, " needs to correspond to a valid")]
+            /// contiguous sequence of `T` values.
+            pub unsafe fn copy_unchecked(&self) -> $ptr<[T]> {
+                self.copy_to_vec_unchecked().into()
+            }
+
+            #[doc = concat!("Create a copy of the data, transmuted into a new ", $what, ". As `T` is")]
+            /// trivially transmutable, and the copy will be properly allocated
+            /// for accessing values of type `T`, this operation is safe and will never fail.
+            pub fn copy(&self) -> $ptr<[T]>
+                where T: TriviallyTransmutable
+            {
+                unsafe {
+                    // no value checks needed thanks to `TriviallyTransmutable`
+                    self.copy_unchecked()
+                }
+            }
+        }
+
+        impl<'a, S, T> From<$name<S, T>> for Error<'a, S, T> {
+            fn from(e: $name<S, T>) -> Self {
+                Error::$variant(e)
+            }
+        }
+
+        impl_incompatible_target_error_fmt!($name, $kind);
     }
 }
 
-#[cfg(feature = "std")]
-impl<S, T> StdError for IncompatibleVecTargetError<S, T> {
-    fn description(&self) -> &str {
-        "incompatible target type"
-    }
+#[cfg(feature = "alloc")]
+incompatible_slice_target_error! {
+    /// Incompatible boxed slice transmutation error.
+    ///
+    /// Returned when the element type `S` does not allow a safe boxed slice
+    /// transmutation to the target type `T`. This happens when either
+    /// the size or minimum memory alignment requirements are not met:
+    ///
+    /// - `std::mem::align_of::<S>() != std::mem::align_of::<T>()`
+    /// - `std::mem::size_of::<S>() != std::mem::size_of::<T>()`
+    pub struct IncompatibleBoxTargetError { boxed: Box },
+    IncompatibleBoxTarget, "boxed slice", "boxed "
 }
 
 #[cfg(feature = "alloc")]
-impl<S, T> fmt::Display for IncompatibleVecTargetError<S, T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f,
-               "incompatible target type (size: {}, align: {}) for transmutation from source (size: {}, align: {})",
-               size_of::<T>(),
-               align_of::<T>(),
-               size_of::<S>(),
-               align_of::<S>())
-    }
+incompatible_slice_target_error! {
+    /// Incompatible reference-counted slice transmutation error.
+    ///
+    /// Returned when the element type `S` does not allow a safe reference-counted slice
+    /// transmutation to the target element type `T`. This happens when either
+    /// the size or minimum memory alignment requirements are not met:
+    ///
+    /// - `std::mem::align_of::<S>() != std::mem::align_of::<T>()`
+    /// - `std::mem::size_of::<S>() != std::mem::size_of::<T>()`
+    pub struct IncompatibleRcTargetError { rc: Rc },
+    IncompatibleRcTarget, "reference-counted slice", "reference-counted "
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+incompatible_slice_target_error! {
+    /// Incompatible atomically reference-counted slice transmutation error.
+    ///
+    /// Returned when the element type `S` does not allow a safe atomically reference-counted slice
+    /// transmutation to the target element type `T`. This happens when either
+    /// the size or minimum memory alignment requirements are not met:
+    ///
+    /// - `std::mem::align_of::<S>() != std::mem::align_of::<T>()`
+    /// - `std::mem::size_of::<S>() != std::mem::size_of::<T>()`
+    ///
+    /// Does not exist on targets without pointer-sized atomics.
+    pub struct IncompatibleArcTargetError { arc: Arc },
+    IncompatibleArcTarget, "atomically reference-counted slice", "atomically reference-counted "
 }
@@ -0,0 +1,163 @@
+//! Extension traits for calling the transmutation functions as methods on byte containers.
+//!
+//! # Examples
+//!
+//! ```
+//! # use safe_transmute::{TransmuteExt, AsBytesExt, PermissiveGuard};
+//! let words = [0x0102u16, 0x0304];
+//! let bytes = words.as_bytes();
+//!
+//! assert_eq!(bytes.transmute_one::<u16>(), Ok(0x0102));
+//! assert_eq!(bytes.transmute_many::<u16, PermissiveGuard>(), Ok(&words[..]));
+//! ```
+
+
+use self::super::full::{transmute_many_mut, transmute_many, transmute_one};
+use self::super::to_bytes::{transmute_to_bytes_mut, transmute_to_bytes};
+use self::super::trivial::TriviallyTransmutable;
+#[cfg(feature = "alloc")]
+use self::super::full::transmute_vec;
+use self::super::guard::Guard;
+use self::super::Error;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+/// Method-call syntax for transmuting the bytes of any `AsRef<[u8]>`,
+/// such as `[u8]`, `Vec<u8>` and `[u8; N]`.
+pub trait TransmuteExt: AsRef<[u8]> {
+    /// Transmute the bytes into a single instance of a trivially transmutable type,
+    /// see [`transmute_one()`](../fn.transmute_one.html).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in one of the following situations:
+    ///
+    /// - The data does not have a memory alignment compatible with `T`.
+    /// - The data does not have enough bytes for a single value `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::TransmuteExt;
+    /// assert_eq!([0xFFu8].transmute_one::<u8>(), Ok(0xFF));
+    /// ```
+    fn transmute_one<'a, T: TriviallyTransmutable>(&'a self) -> Result<T, Error<'a, u8, T>> {
+        transmute_one(self.as_ref())
+    }
+
+    /// Transmute the bytes into a sequence of values of the given type,
+    /// see [`transmute_many()`](../fn.transmute_many.html).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in one of the following situations:
+    ///
+    /// - The data does not have a memory alignment compatible with `T`.
+    /// - The data does not comply with the policies of the given guard `G`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{TransmuteExt, PedanticGuard};
+    /// let bytes = vec![0x00u8, 0x01];
+    /// assert_eq!(bytes.transmute_many::<u8, PedanticGuard>(), Ok(&[0x00, 0x01][..]));
+    /// ```
+    fn transmute_many<'a, T: TriviallyTransmutable, G: Guard>(&'a self) -> Result<&'a [T], Error<'a, u8, T>> {
+        transmute_many::<T, G>(self.as_ref())
+    }
+
+    /// Transmute the bytes into a mutable sequence of values of the given type,
+    /// see [`transmute_many_mut()`](../fn.transmute_many_mut.html).
+    ///
+    /// # Errors
+    ///
+    /// An error is returned in one of the following situations:
+    ///
+    /// - The data does not have a memory alignment compatible with `T`.
+    /// - The data does not comply with the policies of the given guard `G`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::{TransmuteExt, PedanticGuard};
+    /// let mut bytes = [0x00u8, 0x01];
+    /// bytes.transmute_many_mut::<u8, PedanticGuard>().unwrap()[0] = 0xFF;
+    /// assert_eq!(bytes, [0xFF, 0x01]);
+    /// ```
+    fn transmute_many_mut<'a, T: TriviallyTransmutable, G: Guard>(&'a mut self) -> Result<&'a mut [T], Error<'a, u8, T>>
+        where Self: AsMut<[u8]>
+    {
+        transmute_many_mut::<T, G>(self.as_mut())
+    }
+}
+
+impl<B: AsRef<[u8]> + ?Sized> TransmuteExt for B {}
+
+
+/// Method-call syntax for transmuting byte vectors.
+#[cfg(feature = "alloc")]
+pub trait TransmuteVecExt {
+    /// Transmute the vector into a vector of values of the given type,
+    /// reusing its allocation, see [`transmute_vec()`](../fn.transmute_vec.html).
+    ///
+    /// # Errors
+    ///
+    /// An `Error::IncompatibleVecTarget` is returned for every `T` whose size or alignment is not 1,
+    /// whatever the length of the vector, since the allocation could not be handed back with the same layout.
+    /// Its [`copy()`](../error/enum.Error.html#method.copy) then copies the data into a new vector of `T`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::TransmuteVecExt;
+    /// assert_eq!(vec![0x00u8, 0x01].transmute_vec::<u8>(), Ok(vec![0x00, 0x01]));
+    ///
+    /// let words = vec![0x00u8, 0x01].transmute_vec::<u16>().or_else(|e| e.copy());
+    /// assert_eq!(words, Ok(vec![u16::from_be(0x0001)]));
+    /// ```
+    fn transmute_vec<T: TriviallyTransmutable>(self) -> Result<Vec<T>, Error<'static, u8, T>>;
+}
+
+#[cfg(feature = "alloc")]
+impl TransmuteVecExt for Vec<u8> {
+    fn transmute_vec<T: TriviallyTransmutable>(self) -> Result<Vec<T>, Error<'static, u8, T>> {
+        transmute_vec(self)
+    }
+}
+
+
+/// Method-call syntax for viewing a slice of trivially transmutable values as bytes.
+pub trait AsBytesExt {
+    /// View the values as bytes, see [`transmute_to_bytes()`](../fn.transmute_to_bytes.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::AsBytesExt;
+    /// assert_eq!([0x0102u16].as_bytes(), &0x0102u16.to_ne_bytes());
+    /// ```
+    fn as_bytes(&self) -> &[u8];
+
+    /// View the values as mutable bytes, see [`transmute_to_bytes_mut()`](../fn.transmute_to_bytes_mut.html).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::AsBytesExt;
+    /// let mut words = [0x0000u16];
+    /// words.as_bytes_mut()[0] = 0xFF;
+    /// assert_eq!(words, [u16::from_ne_bytes([0xFF, 0x00])]);
+    /// ```
+    fn as_bytes_mut(&mut self) -> &mut [u8];
+}
+
+impl<T: TriviallyTransmutable> AsBytesExt for [T] {
+    fn as_bytes(&self) -> &[u8] {
+        transmute_to_bytes(self)
+    }
+
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        transmute_to_bytes_mut(self)
+    }
+}
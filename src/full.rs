@@ -11,16 +11,42 @@
 //! target type.
 
 
-use self::super::trivial::{TriviallyTransmutable, transmute_trivial, transmute_trivial_many, transmute_trivial_many_mut};
-use self::super::guard::{SingleValueGuard, PermissiveGuard, PedanticGuard, Guard};
-use self::super::align::{check_alignment, check_alignment_mut};
+use self::super::trivial::{transmute_trivial_many_mut_with_guard, transmute_trivial_many_with_guard, TriviallyTransmutable, transmute_trivial};
+use self::super::guard::{SingleValueGuard, check_many_bytes, PermissiveGuard, PedanticGuard, DynGuard, Guard};
+use self::super::policy::AlignmentPolicy;
+use self::super::align::{check_alignment_mut, check_alignment, aligned_split, AlignmentFor, Aligned};
+use self::super::base::{transmute_many_permissive as transmute_many_permissive_unchecked, transmute_many_mut as transmute_many_mut_unchecked};
 #[cfg(feature = "alloc")]
-use self::super::error::IncompatibleVecTargetError;
+use self::super::error::{IncompatibleBoxTargetError, IncompatibleVecTargetError, IncompatibleRcTargetError};
+#[cfg(feature = "alloc")]
+use self::super::base::{transmute_box_slice as transmute_box_slice_unchecked, transmute_rc_slice as transmute_rc_slice_unchecked};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use self::super::base::transmute_arc_slice as transmute_arc_slice_unchecked;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use self::super::error::IncompatibleArcTargetError;
 #[cfg(feature = "alloc")]
 use core::mem::{align_of, size_of, forget};
+#[cfg(feature = "alloc")]
+use core::ptr;
+use self::super::error::{GuardError, copy_to_slice_unchecked};
+use self::super::base::transmuted_len;
 use self::super::Error;
+use core::marker::PhantomData;
+use core::slice;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "allocator_api")]
+use self::super::base::transmute_vec_in as transmute_vec_in_unchecked;
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Allocator;
 
 
 /// Transmute a byte slice into a single instance of a trivially transmutable type.
@@ -111,8 +137,37 @@ pub fn transmute_one_pedantic<T: TriviallyTransmutable>(bytes: &[u8]) -> Result<
 /// # }
 /// ```
 pub fn transmute_many<T: TriviallyTransmutable, G: Guard>(bytes: &[u8]) -> Result<&[T], Error<u8, T>> {
+    transmute_many_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a byte slice into a sequence of values of the given type,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many()`](fn.transmute_many.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not comply with the policies of the given guard `guard`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{transmute_many_with_guard, Error};
+/// # use safe_transmute::guard::ExactCount;
+/// let header_count = 2;
+/// match transmute_many_with_guard::<u16, _>(&[0x00, 0x01, 0x12, 0x24], &ExactCount(header_count)) {
+///     Ok(words) => assert_eq!(words, [u16::from_be(0x0001), u16::from_be(0x1224)]),
+///     Err(Error::Unaligned(_)) => {}
+///     Err(e) => panic!("{}", e),
+/// }
+/// ```
+pub fn transmute_many_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<&'a [T], Error<'a, u8, T>> {
     check_alignment::<_, T>(bytes)?;
-    unsafe { transmute_trivial_many::<_, G>(bytes) }
+    unsafe { transmute_trivial_many_with_guard::<_, G>(bytes, guard) }
 }
 
 /// Transmute a byte slice into a sequence of values of the given type.
@@ -194,9 +249,319 @@ pub fn transmute_many_pedantic<T: TriviallyTransmutable>(bytes: &[u8]) -> Result
 /// # }
 /// ```
 pub fn transmute_many_mut<T: TriviallyTransmutable, G: Guard>(bytes: &mut [u8]) -> Result<&mut [T], Error<u8, T>> {
+    transmute_many_mut_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a mutable byte slice into a mutable sequence of values of the given type,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many_mut()`](fn.transmute_many_mut.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not comply with the policies of the given guard `guard`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{transmute_many_mut_with_guard, Error};
+/// # use safe_transmute::guard::MaxBytes;
+/// match transmute_many_mut_with_guard::<u16, _>(&mut [0x00; 6], &MaxBytes(4)) {
+///     Err(Error::Guard(_)) | Err(Error::Unaligned(_)) => {}
+///     _ => unreachable!(),
+/// }
+/// ```
+pub fn transmute_many_mut_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a mut [u8], guard: &G) -> Result<&'a mut [T], Error<'a, u8, T>> {
     check_alignment_mut::<_, T>(bytes)
         .map_err(Error::from)
-        .and_then(|bytes| unsafe { transmute_trivial_many_mut::<_, G>(bytes) })
+        .and_then(|bytes| unsafe { transmute_trivial_many_mut_with_guard::<_, G>(bytes, guard) })
+}
+
+/// Transmute a byte slice into a sequence of values of the given type,
+/// also returning the trailing bytes which do not fill a whole value.
+///
+/// Unlike [`transmute_many()`](fn.transmute_many.html), bytes which the guard
+/// allows to be left over are never silently dropped.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`. You will
+///   have to make a copy anyway, or modify how the data was originally made.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PermissiveGuard, Error, transmute_many_with_tail};
+/// match transmute_many_with_tail::<u16, PermissiveGuard>(&[0x00, 0x01, 0x12, 0x24, 0xFF]) {
+///     Ok((words, tail)) => {
+///         assert_eq!(words, [u16::from_be(0x0001), u16::from_be(0x1224)]);
+///         assert_eq!(tail, &[0xFF]);
+///     }
+///     Err(Error::Unaligned(_)) => {}
+///     Err(e) => panic!("{}", e),
+/// }
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn transmute_many_with_tail<T: TriviallyTransmutable, G: Guard>(bytes: &[u8]) -> Result<(&[T], &[u8]), Error<'_, u8, T>> {
+    transmute_many_with_tail_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a byte slice into a sequence of values of the given type,
+/// also returning the trailing bytes which do not fill a whole value,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many_with_tail()`](fn.transmute_many_with_tail.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not comply with the policies of the given guard `guard`.
+#[allow(clippy::type_complexity)]
+pub fn transmute_many_with_tail_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a [u8], guard: &G)
+                                                                                       -> Result<(&'a [T], &'a [u8]), Error<'a, u8, T>> {
+    check_alignment::<_, T>(bytes)?;
+    let outcome = guard.check_bytes_outcome::<T>(bytes)?;
+    let (head, tail) = bytes.split_at(bytes.len() - outcome.leftover_bytes);
+    Ok((unsafe { slice::from_raw_parts(head.as_ptr() as *const T, outcome.elements) }, tail))
+}
+
+/// Transmute a mutable byte slice into a mutable sequence of values of the given type,
+/// also returning the trailing bytes which do not fill a whole value.
+///
+/// Unlike [`transmute_many_mut()`](fn.transmute_many_mut.html), bytes which the guard
+/// allows to be left over are never silently dropped.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`. You will
+///   have to make a copy anyway, or modify how the data was originally made.
+/// - The data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PermissiveGuard, Error, transmute_many_mut_with_tail};
+/// let mut bytes = [0x00, 0x00, 0x00, 0x00, 0xFF];
+/// match transmute_many_mut_with_tail::<u16, PermissiveGuard>(&mut bytes) {
+///     Ok((words, tail)) => {
+///         words[1] = u16::from_be(0x1224);
+///         tail[0] = 0xEE;
+///     }
+///     Err(Error::Unaligned(_)) => return,
+///     Err(e) => panic!("{}", e),
+/// }
+/// assert_eq!(bytes, [0x00, 0x00, 0x12, 0x24, 0xEE]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn transmute_many_mut_with_tail<T: TriviallyTransmutable, G: Guard>(bytes: &mut [u8]) -> Result<(&mut [T], &mut [u8]), Error<'_, u8, T>> {
+    transmute_many_mut_with_tail_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a mutable byte slice into a mutable sequence of values of the given type,
+/// also returning the trailing bytes which do not fill a whole value,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many_mut_with_tail()`](fn.transmute_many_mut_with_tail.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have a memory alignment compatible with `T`.
+/// - The data does not comply with the policies of the given guard `guard`.
+#[allow(clippy::type_complexity)]
+pub fn transmute_many_mut_with_tail_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a mut [u8], guard: &G)
+                                                                                           -> Result<(&'a mut [T], &'a mut [u8]), Error<'a, u8, T>> {
+    let bytes = check_alignment_mut::<_, T>(bytes)?;
+    let outcome = guard.check_bytes_outcome::<T>(bytes)?;
+    let (head, tail) = bytes.split_at_mut(bytes.len() - outcome.leftover_bytes);
+    Ok((unsafe { slice::from_raw_parts_mut(head.as_mut_ptr() as *mut T, outcome.elements) }, tail))
+}
+
+/// Transmute the aligned portion of a byte slice into a sequence of values of the given type,
+/// skipping the leading bytes before it.
+///
+/// Returns the skipped prefix, the values, and the trailing bytes which do not fill a whole value.
+/// The prefix is shorter than the alignment of `T`, or all of the data if it is even shorter than that.
+/// This never copies, and never fails due to misalignment.
+///
+/// # Errors
+///
+/// An error is returned if the bytes of the values, without the prefix and the trailing bytes,
+/// do not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PermissiveGuard, AlignOf, Aligned, transmute_many_skip_unaligned};
+/// let bytes = Aligned::<AlignOf<u16>, _>::new([0xFF, 0x00, 0x01, 0x12, 0x24, 0xEE]);
+///
+/// let (prefix, words, suffix) = transmute_many_skip_unaligned::<u16, PermissiveGuard>(&bytes.data[1..]).unwrap();
+/// assert_eq!(prefix, &[0x00]);
+/// assert_eq!(words, [u16::from_be(0x0112), u16::from_be(0x24EE)]);
+/// assert_eq!(suffix, &[]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn transmute_many_skip_unaligned<T: TriviallyTransmutable, G: Guard>(bytes: &[u8]) -> Result<(&[u8], &[T], &[u8]), GuardError> {
+    transmute_many_skip_unaligned_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute the aligned portion of a byte slice into a sequence of values of the given type,
+/// skipping the leading bytes before it, checked against the given guard value.
+///
+/// This is [`transmute_many_skip_unaligned()`](fn.transmute_many_skip_unaligned.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the bytes of the values, without the prefix and the trailing bytes,
+/// do not comply with the policies of the given guard `guard`.
+#[allow(clippy::type_complexity)]
+pub fn transmute_many_skip_unaligned_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a [u8], guard: &G)
+                                                                                            -> Result<(&'a [u8], &'a [T], &'a [u8]), GuardError> {
+    let (prefix_len, middle_len) = aligned_split::<_, T>(bytes);
+    let (prefix, rest) = bytes.split_at(prefix_len);
+    let (middle, suffix) = rest.split_at(middle_len);
+    check_many_bytes::<T, _>(guard, middle)?;
+    // An empty middle may not be aligned, if the data ends before the first aligned position
+    let values = match transmuted_len::<u8, T>(middle_len) {
+        0 => &[],
+        count => unsafe { slice::from_raw_parts(middle.as_ptr() as *const T, count) },
+    };
+    Ok((prefix, values, suffix))
+}
+
+/// Transmute the aligned portion of a mutable byte slice into a mutable sequence of values of the given type,
+/// skipping the leading bytes before it.
+///
+/// Returns the skipped prefix, the values, and the trailing bytes which do not fill a whole value.
+/// The prefix is shorter than the alignment of `T`, or all of the data if it is even shorter than that.
+/// This never copies, and never fails due to misalignment.
+///
+/// # Errors
+///
+/// An error is returned if the bytes of the values, without the prefix and the trailing bytes,
+/// do not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PedanticGuard, AlignOf, Aligned, transmute_many_skip_unaligned_mut};
+/// let mut bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 8]);
+///
+/// let (prefix, words, suffix) = transmute_many_skip_unaligned_mut::<u32, PedanticGuard>(&mut bytes.data[1..]).unwrap();
+/// prefix.copy_from_slice(&[0xFF; 3]);
+/// words[0] = u32::from_be(0x0102_0304);
+/// assert!(suffix.is_empty());
+///
+/// assert_eq!(bytes.data, [0x00, 0xFF, 0xFF, 0xFF, 0x01, 0x02, 0x03, 0x04]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn transmute_many_skip_unaligned_mut<T: TriviallyTransmutable, G: Guard>(bytes: &mut [u8]) -> Result<(&mut [u8], &mut [T], &mut [u8]), GuardError> {
+    transmute_many_skip_unaligned_mut_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute the aligned portion of a mutable byte slice into a mutable sequence of values of the given type,
+/// skipping the leading bytes before it, checked against the given guard value.
+///
+/// This is [`transmute_many_skip_unaligned_mut()`](fn.transmute_many_skip_unaligned_mut.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the bytes of the values, without the prefix and the trailing bytes,
+/// do not comply with the policies of the given guard `guard`.
+#[allow(clippy::type_complexity)]
+pub fn transmute_many_skip_unaligned_mut_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a mut [u8], guard: &G)
+                                                                                                -> Result<(&'a mut [u8], &'a mut [T], &'a mut [u8]), GuardError> {
+    let (prefix_len, middle_len) = aligned_split::<_, T>(bytes);
+    let (prefix, rest) = bytes.split_at_mut(prefix_len);
+    let (middle, suffix) = rest.split_at_mut(middle_len);
+    check_many_bytes::<T, _>(guard, middle)?;
+    // An empty middle may not be aligned, if the data ends before the first aligned position
+    let values = match transmuted_len::<u8, T>(middle_len) {
+        0 => &mut [],
+        count => unsafe { slice::from_raw_parts_mut(middle.as_mut_ptr() as *mut T, count) },
+    };
+    Ok((prefix, values, suffix))
+}
+
+/// Transmute a byte slice into values of the given type,
+/// handling misaligned data as prescribed by the alignment policy `P`.
+///
+/// See the [`policy`](policy/index.html) module for the available policies and their outputs,
+/// and the [`Transmute`](builder/struct.Transmute.html) builder for combining them with value validation.
+///
+/// There is no mutable variant: the outputs of policies such as
+/// [`CopyOnMisalign`](policy/struct.CopyOnMisalign.html) and [`Unaligned`](policy/struct.Unaligned.html)
+/// are copies rather than views of the data, so writes through them could never reach it.
+/// Use [`transmute_many_skip_unaligned_mut()`](fn.transmute_many_skip_unaligned_mut.html)
+/// or [`transmute_many_mut()`](fn.transmute_many_mut.html) instead.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not comply with the policies of the given guard `G`.
+/// - The data does not have a memory alignment compatible with `T`,
+///   and the policy `P` does not handle it.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PermissiveGuard, AlignOf, Aligned, transmute_many_with_policy};
+/// # use safe_transmute::policy::SkipPrefix;
+/// let bytes = Aligned::<AlignOf<u32>, _>::new([0xFF, 0x01, 0x02, 0x03, 0x04, 0x05]);
+///
+/// assert_eq!(transmute_many_with_policy::<u32, PermissiveGuard, SkipPrefix>(&bytes.data),
+///            Ok(&[u32::from_be(0xFF01_0203)][..]));
+/// assert_eq!(transmute_many_with_policy::<u32, PermissiveGuard, SkipPrefix>(&bytes.data[1..]),
+///            Ok(&[][..]));
+/// ```
+pub fn transmute_many_with_policy<'a, T: TriviallyTransmutable + 'a, G: Guard, P: AlignmentPolicy<'a, T>>(bytes: &'a [u8])
+                                                                                                         -> Result<P::Output, Error<'a, u8, T>> {
+    transmute_many_with_policy_with_guard::<T, _, P>(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a byte slice into values of the given type, checked against the given guard value,
+/// handling misaligned data as prescribed by the alignment policy `P`.
+///
+/// This is [`transmute_many_with_policy()`](fn.transmute_many_with_policy.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not comply with the policies of the given guard `guard`.
+/// - The data does not have a memory alignment compatible with `T`,
+///   and the policy `P` does not handle it.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_many_with_policy_with_guard;
+/// # use safe_transmute::policy::Unaligned;
+/// # use safe_transmute::guard::ExactCount;
+/// let words = transmute_many_with_policy_with_guard::<u16, _, Unaligned>(&[0x00, 0x01, 0x12, 0x24], &ExactCount(2)).unwrap();
+/// assert_eq!(words.len(), 2);
+/// ```
+pub fn transmute_many_with_policy_with_guard<'a, T: TriviallyTransmutable + 'a, G: DynGuard, P: AlignmentPolicy<'a, T>>(bytes: &'a [u8], guard: &G)
+                                                                                                                       -> Result<P::Output, Error<'a, u8, T>> {
+    P::apply(bytes, guard)
 }
 
 /// Transmute a byte slice into a sequence of values of the given type.
@@ -298,3 +663,805 @@ pub fn transmute_vec<S: TriviallyTransmutable, T: TriviallyTransmutable>(mut vec
         Ok(Vec::from_raw_parts(ptr as *mut T, len, capacity))
     }
 }
+
+/// Transform a boxed slice into a boxed slice of values with the given target type.
+///
+/// The resulting box will reuse the allocated byte buffer when successful.
+///
+/// # Errors
+///
+/// An error is returned if *either* the size or the minimum memory
+/// requirements are not the same between `S` and `T`:
+///
+/// - `std::mem::size_of::<S>() != std::mem::size_of::<T>()`
+/// - `std::mem::align_of::<S>() != std::mem::align_of::<T>()`
+///
+/// Otherwise, the only truly safe way of doing this is to create a transmuted
+/// slice view of the box, or make a copy anyway. The
+/// [`IncompatibleBoxTargetError`](../error/struct.IncompatibleBoxTargetError.html) error
+/// type provides a means of making this copy to the intended target type.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_box_slice;
+/// # use safe_transmute::error::Error;
+/// # fn run() -> Result<(), Error<'static, u8, i8>> {
+/// assert_eq!(transmute_box_slice::<u8, i8>(vec![0x00, 0x01, 0x00, 0x02].into_boxed_slice())?,
+///            vec![0x00i8, 0x01i8, 0x00i8, 0x02i8].into_boxed_slice());
+///
+/// let words = match transmute_box_slice::<u8, u16>(vec![0x00, 0x01, 0x00, 0x02].into_boxed_slice()) {
+///     Ok(words) => words,
+///     Err(Error::IncompatibleBoxTarget(e)) => e.copy(),
+///     Err(e) => panic!("Unexpected error: {}", e),
+/// };
+/// assert_eq!(*words, [u16::from_be(0x0001), u16::from_be(0x0002)]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_box_slice<S: TriviallyTransmutable, T: TriviallyTransmutable>(boxed: Box<[S]>) -> Result<Box<[T]>, Error<'static, S, T>> {
+    if align_of::<S>() != align_of::<T>() || size_of::<S>() != size_of::<T>() {
+        return Err(IncompatibleBoxTargetError::new(boxed).into());
+    }
+
+    unsafe { Ok(transmute_box_slice_unchecked::<S, T>(boxed)) }
+}
+
+/// Transform a boxed byte slice into a single boxed instance of a trivially transmutable type.
+///
+/// The boxed slice must have exactly enough bytes to fill a single instance of a type,
+/// and the resulting box will reuse the allocated byte buffer when successful.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not have exactly enough bytes for a single value `T`.
+/// - The minimum memory alignment requirements of `T` are not the same as of `u8`,
+///   i.e. `std::mem::align_of::<T>() != 1`.
+///   The [`IncompatibleBoxTargetError`](../error/struct.IncompatibleBoxTargetError.html)
+///   error type provides a means of making a copy to a one-element boxed slice.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_box;
+/// # use safe_transmute::error::Error;
+/// # fn run() -> Result<(), Error<'static, u8, [u8; 4]>> {
+/// assert_eq!(transmute_box::<[u8; 4]>(vec![0x00, 0x01, 0x00, 0x02].into_boxed_slice())?,
+///            Box::new([0x00, 0x01, 0x00, 0x02]));
+///
+/// let word = match transmute_box::<u32>(vec![0x00, 0x01, 0x00, 0x02].into_boxed_slice()) {
+///     Ok(word) => *word,
+///     Err(Error::IncompatibleBoxTarget(e)) => e.copy()[0],
+///     Err(e) => panic!("Unexpected error: {}", e),
+/// };
+/// assert_eq!(word, u32::from_be(0x0001_0002));
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_box<T: TriviallyTransmutable>(bytes: Box<[u8]>) -> Result<Box<T>, Error<'static, u8, T>> {
+    SingleValueGuard::check::<T>(&bytes)?;
+    if align_of::<T>() != align_of::<u8>() {
+        return Err(IncompatibleBoxTargetError::new(bytes).into());
+    }
+
+    unsafe { Ok(Box::from_raw(Box::into_raw(bytes) as *mut T)) }
+}
+
+/// Transform a reference-counted slice into a reference-counted slice of values with the given target type.
+///
+/// The resulting Rc will share the allocation with the source one when successful,
+/// without copying the data.
+///
+/// # Errors
+///
+/// An error is returned if *either* the size or the minimum memory
+/// requirements are not the same between `S` and `T`:
+///
+/// - `std::mem::size_of::<S>() != std::mem::size_of::<T>()`
+/// - `std::mem::align_of::<S>() != std::mem::align_of::<T>()`
+///
+/// Otherwise, the only truly safe way of doing this is to create a transmuted
+/// slice view of the Rc, or make a copy anyway. The
+/// [`IncompatibleRcTargetError`](../error/struct.IncompatibleRcTargetError.html) error
+/// type hands the original Rc back, and provides a means of making this copy to the intended target type.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_rc_slice;
+/// # use safe_transmute::error::Error;
+/// # use std::rc::Rc;
+/// # fn run() -> Result<(), Error<'static, u8, i8>> {
+/// let bytes: Rc<[u8]> = Rc::from(&[0x00, 0x01, 0x00, 0xFF][..]);
+/// assert_eq!(*transmute_rc_slice::<u8, i8>(bytes.clone())?, [0x00i8, 0x01i8, 0x00i8, -0x01i8]);
+///
+/// let words: Rc<[u16]> = match transmute_rc_slice::<u8, u16>(bytes) {
+///     Ok(words) => words,
+///     Err(Error::IncompatibleRcTarget(e)) => e.copy(),
+///     Err(e) => panic!("Unexpected error: {}", e),
+/// };
+/// assert_eq!(*words, [u16::from_be(0x0001), u16::from_be(0x00FF)]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_rc_slice<S: TriviallyTransmutable, T: TriviallyTransmutable>(rc: Rc<[S]>) -> Result<Rc<[T]>, Error<'static, S, T>> {
+    if align_of::<S>() != align_of::<T>() || size_of::<S>() != size_of::<T>() {
+        return Err(IncompatibleRcTargetError::new(rc).into());
+    }
+
+    unsafe { Ok(transmute_rc_slice_unchecked::<S, T>(rc)) }
+}
+
+/// Transform an atomically reference-counted slice into an atomically reference-counted slice of values with the given target type.
+///
+/// The resulting Arc will share the allocation with the source one when successful,
+/// without copying the data.
+///
+/// # Errors
+///
+/// An error is returned if *either* the size or the minimum memory
+/// requirements are not the same between `S` and `T`:
+///
+/// - `std::mem::size_of::<S>() != std::mem::size_of::<T>()`
+/// - `std::mem::align_of::<S>() != std::mem::align_of::<T>()`
+///
+/// Otherwise, the only truly safe way of doing this is to create a transmuted
+/// slice view of the Arc, or make a copy anyway. The
+/// [`IncompatibleArcTargetError`](../error/struct.IncompatibleArcTargetError.html) error
+/// type hands the original Arc back, and provides a means of making this copy to the intended target type.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_arc_slice;
+/// # use safe_transmute::error::Error;
+/// # use std::sync::Arc;
+/// # fn run() -> Result<(), Error<'static, u8, i8>> {
+/// let bytes: Arc<[u8]> = Arc::from(&[0x00, 0x01, 0x00, 0xFF][..]);
+/// assert_eq!(*transmute_arc_slice::<u8, i8>(bytes.clone())?, [0x00i8, 0x01i8, 0x00i8, -0x01i8]);
+///
+/// let words: Arc<[u16]> = match transmute_arc_slice::<u8, u16>(bytes) {
+///     Ok(words) => words,
+///     Err(Error::IncompatibleArcTarget(e)) => e.copy(),
+///     Err(e) => panic!("Unexpected error: {}", e),
+/// };
+/// assert_eq!(*words, [u16::from_be(0x0001), u16::from_be(0x00FF)]);
+/// # Ok(())
+/// # }
+/// # run().unwrap();
+/// ```
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub fn transmute_arc_slice<S: TriviallyTransmutable, T: TriviallyTransmutable>(arc: Arc<[S]>) -> Result<Arc<[T]>, Error<'static, S, T>> {
+    if align_of::<S>() != align_of::<T>() || size_of::<S>() != size_of::<T>() {
+        return Err(IncompatibleArcTargetError::new(arc).into());
+    }
+
+    unsafe { Ok(transmute_arc_slice_unchecked::<S, T>(arc)) }
+}
+
+/// Transform a vector in a custom allocator into a vector of values with the given target type,
+/// in the same allocator.
+///
+/// The resulting vector will reuse the allocated byte buffer when successful.
+///
+/// Requires the `allocator_api` feature, and thus a nightly rustc.
+///
+/// # Errors
+///
+/// An error is returned if *either* the size or the minimum memory
+/// requirements are not the same between `S` and `T`:
+///
+/// - `std::mem::size_of::<S>() != std::mem::size_of::<T>()`
+/// - `std::mem::align_of::<S>() != std::mem::align_of::<T>()`
+///
+/// The [`IncompatibleVecTargetError`](../error/struct.IncompatibleVecTargetError.html) error
+/// type hands the original vector back, and provides a means of making a copy in the same allocator.
+///
+/// Unlike [`transmute_vec()`](fn.transmute_vec.html), this returns the `IncompatibleVecTargetError` itself
+/// rather than an [`Error`](../error/enum.Error.html), as the latter cannot hold a vector in a custom allocator.
+/// For the global allocator, the error converts into an `Error` with `From`, so `?` works the same for both functions.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(allocator_api)]
+/// # use safe_transmute::transmute_vec_in;
+/// # use std::alloc::Global;
+/// let mut bytes = Vec::new_in(Global);
+/// bytes.extend_from_slice(&[0x00u8, 0x01, 0x00, 0x02]);
+///
+/// let words: Vec<u16, Global> = match transmute_vec_in::<u8, u16, _>(bytes.clone()) {
+///     Ok(words) => words,
+///     Err(e) => e.copy(),
+/// };
+/// assert_eq!(words, [u16::from_be(0x0001), u16::from_be(0x0002)]);
+///
+/// assert_eq!(transmute_vec_in::<u8, i8, _>(bytes).unwrap(), [0x00i8, 0x01i8, 0x00i8, 0x02i8]);
+/// ```
+#[cfg(feature = "allocator_api")]
+pub fn transmute_vec_in<S: TriviallyTransmutable, T: TriviallyTransmutable, A: Allocator>(vec: Vec<S, A>)
+                                                                                       -> Result<Vec<T, A>, IncompatibleVecTargetError<S, T, A>> {
+    if align_of::<S>() != align_of::<T>() || size_of::<S>() != size_of::<T>() {
+        return Err(IncompatibleVecTargetError::new(vec));
+    }
+
+    unsafe { Ok(transmute_vec_in_unchecked::<S, T, A>(vec)) }
+}
+
+/// View a byte slice as a sequence of values of the given type,
+/// copying the data if it is not properly aligned for `T`.
+///
+/// This is a function counterpart to [`try_copy!()`](../macro.try_copy.html),
+/// usable regardless of the error type of the caller.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, transmute_many_or_copy};
+/// let bytes = &[0x00, 0x01, 0x12, 0x24,
+///               0x00]; // 1 spare byte
+/// let words = transmute_many_or_copy::<u16, SingleManyGuard>(bytes).unwrap();
+///
+/// assert_eq!(*words,
+///            [u16::from_be(0x0001), u16::from_be(0x1224)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_many_or_copy<T: TriviallyTransmutable, G: Guard>(bytes: &[u8]) -> Result<Cow<'_, [T]>, GuardError> {
+    transmute_many_or_copy_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a byte slice into a sequence of values of the given type, copying it if unaligned,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many_or_copy()`](fn.transmute_many_or_copy.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the given guard `guard`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_many_or_copy_with_guard;
+/// # use safe_transmute::guard::CountRange;
+/// let words = transmute_many_or_copy_with_guard::<u16, _>(&[0x00, 0x01, 0x12, 0x24], &CountRange(1..=2)).unwrap();
+/// assert_eq!(*words, [u16::from_be(0x0001), u16::from_be(0x1224)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_many_or_copy_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<Cow<'a, [T]>, GuardError> {
+    check_many_bytes::<T, _>(guard, bytes)?;
+    Ok(match check_alignment::<_, T>(bytes) {
+        Ok(()) => Cow::Borrowed(unsafe { transmute_many_permissive_unchecked(bytes) }),
+        Err(e) => Cow::Owned(e.copy()),
+    })
+}
+
+/// View a byte slice as a sequence of values of the given type,
+/// copying the data if it is not properly aligned for `T`.
+///
+/// The resulting slice will have as many instances of a type as will fit, rounded down.
+/// The permissive guard is a no-op, which makes it possible for this function to return
+/// the values directly.
+///
+/// # Panics
+///
+/// This function panics if `T` is zero-sized, see
+/// [the crate documentation](index.html#zero-sized-types).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_many_permissive_or_copy;
+/// assert_eq!(*transmute_many_permissive_or_copy::<u16>(&[0x00, 0x01, 0x12]),
+///            [u16::from_be(0x0001)]);
+/// assert_eq!(*transmute_many_permissive_or_copy::<u16>(&[0x00]), []);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_many_permissive_or_copy<T: TriviallyTransmutable>(bytes: &[u8]) -> Cow<'_, [T]> {
+    transmute_many_or_copy::<T, PermissiveGuard>(bytes).expect("permissive guard should never fail")
+}
+
+/// View a byte slice as a sequence of values of the given type,
+/// copying the data if it is not properly aligned for `T`.
+///
+/// # Errors
+///
+/// An error is returned if the data does not have enough bytes for a single value `T`,
+/// or has extraneous bytes at the end.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_many_pedantic_or_copy;
+/// assert_eq!(*transmute_many_pedantic_or_copy::<u16>(&[0x00, 0x01, 0x12, 0x24]).unwrap(),
+///            [u16::from_be(0x0001), u16::from_be(0x1224)]);
+/// assert!(transmute_many_pedantic_or_copy::<u16>(&[0x00, 0x01, 0x12]).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_many_pedantic_or_copy<T: TriviallyTransmutable>(bytes: &[u8]) -> Result<Cow<'_, [T]>, GuardError> {
+    transmute_many_or_copy::<T, PedanticGuard>(bytes)
+}
+
+/// View a byte slice as a sequence of values of an arbitrary type,
+/// copying the data if it is not properly aligned for `T`.
+///
+/// Equivalent to [`transmute_many_or_copy()`](fn.transmute_many_or_copy.html),
+/// except for not checking that the target type is trivially transmutable.
+///
+/// # Safety
+///
+/// The byte data needs to correspond to a valid contiguous sequence of `T` values.
+/// Types `T` with a `Drop` implementation are unlikely to be safe in this regard.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, transmute_many_or_copy_unchecked};
+/// unsafe {
+///     assert_eq!(*transmute_many_or_copy_unchecked::<u16, SingleManyGuard>(&[0x00, 0x01, 0x12, 0x24]).unwrap(),
+///                [u16::from_be(0x0001), u16::from_be(0x1224)]);
+/// }
+/// ```
+#[cfg(feature = "alloc")]
+pub unsafe fn transmute_many_or_copy_unchecked<T: Clone, G: Guard>(bytes: &[u8]) -> Result<Cow<'_, [T]>, GuardError> {
+    transmute_many_or_copy_unchecked_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a byte slice into a sequence of values of an arbitrary type, copying it if unaligned,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many_or_copy_unchecked()`](fn.transmute_many_or_copy_unchecked.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Safety
+///
+/// See [`transmute_many_or_copy_unchecked()`](fn.transmute_many_or_copy_unchecked.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the given guard `guard`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_many_or_copy_unchecked_with_guard;
+/// # use safe_transmute::guard::ExactCount;
+/// let words = unsafe { transmute_many_or_copy_unchecked_with_guard::<u16, _>(&[0x00, 0x01], &ExactCount(1)) }.unwrap();
+/// assert_eq!(*words, [u16::from_be(0x0001)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub unsafe fn transmute_many_or_copy_unchecked_with_guard<'a, T: Clone, G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<Cow<'a, [T]>, GuardError> {
+    check_many_bytes::<T, _>(guard, bytes)?;
+    Ok(match check_alignment::<_, T>(bytes) {
+        Ok(()) => Cow::Borrowed(transmute_many_permissive_unchecked(bytes)),
+        Err(e) => Cow::Owned(e.copy_unchecked()),
+    })
+}
+
+/// Transmute a borrowed or owned byte slice into a borrowed or owned sequence of values of the given type,
+/// copying only if there is no other way.
+///
+/// - Borrowed data is viewed in place if it is properly aligned for `T`,
+///   and copied otherwise, as per [`transmute_many_or_copy()`](fn.transmute_many_or_copy.html).
+/// - Owned data reuses the vector's allocated byte buffer if `T` is compatible with `u8`,
+///   as per [`transmute_vec()`](fn.transmute_vec.html), and is copied otherwise.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, transmute_cow};
+/// # use std::borrow::Cow;
+/// let words = transmute_cow::<u16, SingleManyGuard>(Cow::Borrowed(&[0x00, 0x01, 0x12, 0x24])).unwrap();
+/// assert_eq!(*words, [u16::from_be(0x0001), u16::from_be(0x1224)]);
+///
+/// let signed = transmute_cow::<i8, SingleManyGuard>(Cow::Owned(vec![0x00, 0xFF])).unwrap();
+/// assert_eq!(signed, Cow::Owned::<[i8]>(vec![0x00, -0x01]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_cow<T: TriviallyTransmutable, G: Guard>(bytes: Cow<'_, [u8]>) -> Result<Cow<'_, [T]>, Error<'_, u8, T>> {
+    transmute_cow_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a byte buffer which may be borrowed or owned into a sequence of values of the given type,
+/// checked against the given guard value.
+///
+/// This is [`transmute_cow()`](fn.transmute_cow.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the given guard `guard`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_cow_with_guard;
+/// # use safe_transmute::guard::ExactCount;
+/// # use std::borrow::Cow;
+/// let signed = transmute_cow_with_guard::<i8, _>(Cow::Owned(vec![0x00, 0xFF]), &ExactCount(2)).unwrap();
+/// assert_eq!(signed, Cow::Owned::<[i8]>(vec![0x00, -0x01]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_cow_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: Cow<'a, [u8]>, guard: &G) -> Result<Cow<'a, [T]>, Error<'a, u8, T>> {
+    match bytes {
+        Cow::Borrowed(bytes) => Ok(transmute_many_or_copy_with_guard::<T, G>(bytes, guard)?),
+        Cow::Owned(bytes) => {
+            check_many_bytes::<T, _>(guard, &bytes)?;
+            transmute_vec::<u8, T>(bytes).or_else(Error::copy).map(Cow::Owned)
+        }
+    }
+}
+
+/// Append the values in a byte slice to a vector of the given type, regardless of alignment.
+///
+/// Capacity for the new values is reserved up front, and the bytes are copied directly
+/// into the vector's spare capacity. Any trailing bytes which do not fill a whole value
+/// are ignored, see [`extend_from_bytes_with_tail()`](fn.extend_from_bytes_with_tail.html)
+/// for retrieving them.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`, in which case the vector is left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PermissiveGuard, PedanticGuard, extend_from_bytes};
+/// let mut words = vec![0x0001u16];
+/// extend_from_bytes::<u16, PedanticGuard>(&mut words, &[0x12, 0x24, 0x00, 0x20]).unwrap();
+/// assert_eq!(words, [0x0001, u16::from_be(0x1224), u16::from_be(0x0020)]);
+///
+/// assert!(extend_from_bytes::<u16, PedanticGuard>(&mut words, &[0xFF]).is_err());
+/// extend_from_bytes::<u16, PermissiveGuard>(&mut words, &[0xFF]).unwrap();
+/// assert_eq!(words.len(), 3);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn extend_from_bytes<'a, T: TriviallyTransmutable, G: Guard>(vec: &mut Vec<T>, bytes: &'a [u8]) -> Result<(), Error<'a, u8, T>> {
+    extend_from_bytes_with_guard(vec, bytes, &PhantomData::<G>)
+}
+
+/// Append the values in a byte slice to a vector of the given type, regardless of alignment,
+/// checked against the given guard value.
+///
+/// This is [`extend_from_bytes()`](fn.extend_from_bytes.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `guard`, in which case the vector is left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::extend_from_bytes_with_guard;
+/// # use safe_transmute::guard::MaxBytes;
+/// let mut words = vec![0x0001u16];
+/// assert!(extend_from_bytes_with_guard::<u16, _>(&mut words, &[0x00; 8], &MaxBytes(4)).is_err());
+/// assert_eq!(words, [0x0001]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn extend_from_bytes_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(vec: &mut Vec<T>, bytes: &'a [u8], guard: &G) -> Result<(), Error<'a, u8, T>> {
+    extend_from_bytes_with_tail_with_guard::<T, G>(vec, bytes, guard).map(|_| ())
+}
+
+/// Append the values in a byte slice to a vector of the given type, regardless of alignment,
+/// returning the trailing bytes which do not fill a whole value.
+///
+/// This allows carrying a partial value over to the next chunk of a stream of bytes.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`, in which case the vector is left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PermissiveGuard, extend_from_bytes_with_tail};
+/// let mut words = Vec::new();
+/// let tail = extend_from_bytes_with_tail::<u16, PermissiveGuard>(&mut words, &[0x12, 0x24, 0x00]).unwrap();
+/// assert_eq!(words, [u16::from_be(0x1224)]);
+/// assert_eq!(tail, &[0x00]);
+///
+/// let chunk = [tail[0], 0x20];
+/// assert_eq!(extend_from_bytes_with_tail::<u16, PermissiveGuard>(&mut words, &chunk).unwrap(), &[]);
+/// assert_eq!(words, [u16::from_be(0x1224), u16::from_be(0x0020)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn extend_from_bytes_with_tail<'a, T: TriviallyTransmutable, G: Guard>(vec: &mut Vec<T>, bytes: &'a [u8]) -> Result<&'a [u8], Error<'a, u8, T>> {
+    extend_from_bytes_with_tail_with_guard(vec, bytes, &PhantomData::<G>)
+}
+
+/// Append the values in a byte slice to a vector of the given type, regardless of alignment,
+/// returning the trailing bytes which do not fill a whole value,
+/// checked against the given guard value.
+///
+/// This is [`extend_from_bytes_with_tail()`](fn.extend_from_bytes_with_tail.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `guard`, in which case the vector is left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::extend_from_bytes_with_tail_with_guard;
+/// # use safe_transmute::guard::CountRange;
+/// let mut words = Vec::new();
+/// let tail = extend_from_bytes_with_tail_with_guard::<u16, _>(&mut words, &[0x12, 0x24, 0x00], &CountRange(1..=2)).unwrap();
+/// assert_eq!(words, [u16::from_be(0x1224)]);
+/// assert_eq!(tail, &[0x00]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn extend_from_bytes_with_tail_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(vec: &mut Vec<T>, bytes: &'a [u8], guard: &G) -> Result<&'a [u8], Error<'a, u8, T>> {
+    check_many_bytes::<T, _>(guard, bytes)?;
+
+    let len = transmuted_len::<u8, T>(bytes.len());
+    let byte_len = len * size_of::<T>();
+    vec.reserve(len);
+    unsafe {
+        ptr::copy_nonoverlapping(bytes.as_ptr(), vec.as_mut_ptr().add(vec.len()) as *mut u8, byte_len);
+        vec.set_len(vec.len() + len);
+    }
+
+    Ok(&bytes[byte_len..])
+}
+
+/// Copy a byte slice into the start of a slice of values of the given type, regardless of alignment.
+///
+/// This does not allocate, and thus makes a way out of unaligned data in `no-alloc` environments,
+/// such as into stack or static storage.
+///
+/// [`copy_from_bytes()`](fn.copy_from_bytes.html) is the same with the destination first.
+///
+/// Returns the amount of `T` values written.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not comply with the policies of the given guard `G`.
+/// - The destination is too short to hold all values, with `ErrorReason::NotEnoughBytes`; the
+///   error's `required` and `actual` fields then describe the destination size in bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{SingleManyGuard, transmute_many_into};
+/// let mut words = [0u16; 4];
+/// assert_eq!(transmute_many_into::<u16, SingleManyGuard>(&[0x00, 0x01, 0x12, 0x24, 0x00], &mut words), Ok(2));
+/// assert_eq!(words, [u16::from_be(0x0001), u16::from_be(0x1224), 0, 0]);
+/// ```
+pub fn transmute_many_into<T: TriviallyTransmutable, G: Guard>(bytes: &[u8], dst: &mut [T]) -> Result<usize, GuardError> {
+    transmute_many_into_with_guard(bytes, dst, &PhantomData::<G>)
+}
+
+/// Copy a byte slice into the start of a slice of values of the given type, regardless of alignment,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many_into()`](fn.transmute_many_into.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The data does not comply with the policies of the given guard `guard`.
+/// - The destination is too short to hold all values, with `ErrorReason::NotEnoughBytes`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_many_into_with_guard;
+/// # use safe_transmute::guard::ExactCount;
+/// let mut words = [0u16; 4];
+/// assert_eq!(transmute_many_into_with_guard::<u16, _>(&[0x00, 0x01, 0x12, 0x24], &mut words, &ExactCount(2)), Ok(2));
+/// assert_eq!(words, [u16::from_be(0x0001), u16::from_be(0x1224), 0, 0]);
+/// ```
+pub fn transmute_many_into_with_guard<T: TriviallyTransmutable, G: DynGuard>(bytes: &[u8], dst: &mut [T], guard: &G) -> Result<usize, GuardError> {
+    check_many_bytes::<T, _>(guard, bytes)?;
+    unsafe { copy_to_slice_unchecked::<u8, T>(bytes, dst) }
+}
+
+/// Fill the start of a slice of values of the given type from a byte slice, regardless of alignment.
+///
+/// This is an alias of [`transmute_many_into()`](fn.transmute_many_into.html) with the destination first,
+/// mirroring `<[T]>::copy_from_slice()`: a reusable buffer can be refilled
+/// without allocating or resorting to unsafe code.
+///
+/// Returns the amount of `T` values written.
+///
+/// # Errors
+///
+/// The same as for [`transmute_many_into()`](fn.transmute_many_into.html).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PedanticGuard, copy_from_bytes};
+/// let mut words = [0u16; 2];
+/// assert_eq!(copy_from_bytes::<u16, PedanticGuard>(&mut words, &[0x00, 0x01, 0x12, 0x24]), Ok(2));
+/// assert_eq!(words, [u16::from_be(0x0001), u16::from_be(0x1224)]);
+///
+/// assert_eq!(copy_from_bytes::<u16, PedanticGuard>(&mut words, &[0xFF, 0xFF]), Ok(1));
+/// assert_eq!(words, [0xFFFF, u16::from_be(0x1224)]);
+/// ```
+pub fn copy_from_bytes<T: TriviallyTransmutable, G: Guard>(dst: &mut [T], src: &[u8]) -> Result<usize, GuardError> {
+    transmute_many_into::<T, G>(src, dst)
+}
+
+/// Fill the start of a slice of values of the given type from a byte slice, regardless of alignment,
+/// checked against the given guard value.
+///
+/// This is an alias of [`transmute_many_into_with_guard()`](fn.transmute_many_into_with_guard.html)
+/// with the destination first, see [`copy_from_bytes()`](fn.copy_from_bytes.html).
+///
+/// # Errors
+///
+/// The same as for [`transmute_many_into_with_guard()`](fn.transmute_many_into_with_guard.html).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::copy_from_bytes_with_guard;
+/// # use safe_transmute::guard::MaxBytes;
+/// let mut words = [0u16; 2];
+/// assert_eq!(copy_from_bytes_with_guard::<u16, _>(&mut words, &[0x00, 0x01], &MaxBytes(4)), Ok(1));
+/// assert_eq!(words, [u16::from_be(0x0001), 0]);
+/// ```
+pub fn copy_from_bytes_with_guard<T: TriviallyTransmutable, G: DynGuard>(dst: &mut [T], src: &[u8], guard: &G) -> Result<usize, GuardError> {
+    transmute_many_into_with_guard::<T, G>(src, dst, guard)
+}
+
+/// Transmute a statically aligned byte slice into a sequence of values of the given type.
+///
+/// Since the alignment type `A` is known to be sufficient for `T`, this can
+/// never fail due to unaligned data.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::{Aligned, Align4};
+/// # use safe_transmute::{PedanticGuard, transmute_many_aligned};
+/// let bytes = Aligned::<Align4, _>::new([0x00, 0x01, 0x12, 0x24]);
+/// assert_eq!(transmute_many_aligned::<u16, PedanticGuard, _>(&bytes),
+///            Ok(&[u16::from_be(0x0001), u16::from_be(0x1224)][..]));
+/// ```
+pub fn transmute_many_aligned<T: TriviallyTransmutable, G: Guard, A: AlignmentFor<T>>(bytes: &Aligned<A, [u8]>) -> Result<&[T], GuardError> {
+    transmute_many_aligned_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a statically aligned byte slice into a sequence of values of the given type,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many_aligned()`](fn.transmute_many_aligned.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the given guard `guard`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::{Aligned, Align4};
+/// # use safe_transmute::transmute_many_aligned_with_guard;
+/// # use safe_transmute::guard::ExactCount;
+/// let bytes = Aligned::<Align4, _>::new([0x00, 0x01, 0x12, 0x24]);
+/// assert_eq!(transmute_many_aligned_with_guard::<u16, _, _>(&bytes, &ExactCount(2)),
+///            Ok(&[u16::from_be(0x0001), u16::from_be(0x1224)][..]));
+/// ```
+pub fn transmute_many_aligned_with_guard<'a, T: TriviallyTransmutable, G: DynGuard, A: AlignmentFor<T>>(bytes: &'a Aligned<A, [u8]>, guard: &G)
+                                                                                                      -> Result<&'a [T], GuardError> {
+    check_many_bytes::<T, _>(guard, &bytes.data)?;
+    Ok(transmute_many_permissive_aligned(bytes))
+}
+
+/// Transmute a mutable, statically aligned byte slice into a mutable sequence of values of the given type.
+///
+/// Since the alignment type `A` is known to be sufficient for `T`, this can
+/// never fail due to unaligned data.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the given guard `G`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::{Aligned, AlignOf};
+/// # use safe_transmute::{PedanticGuard, transmute_many_aligned_mut};
+/// let mut bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 8]);
+/// transmute_many_aligned_mut::<u32, PedanticGuard, _>(&mut bytes).unwrap()[1] = u32::from_be(0x0102_0304);
+/// assert_eq!(bytes.data, [0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x03, 0x04]);
+/// ```
+pub fn transmute_many_aligned_mut<T: TriviallyTransmutable, G: Guard, A: AlignmentFor<T>>(bytes: &mut Aligned<A, [u8]>) -> Result<&mut [T], GuardError> {
+    transmute_many_aligned_mut_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a mutable, statically aligned byte slice into a mutable sequence of values of the given type,
+/// checked against the given guard value.
+///
+/// This is [`transmute_many_aligned_mut()`](fn.transmute_many_aligned_mut.html) with a runtime guard,
+/// see [`DynGuard`](guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the given guard `guard`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::{Aligned, AlignOf};
+/// # use safe_transmute::transmute_many_aligned_mut_with_guard;
+/// # use safe_transmute::guard::ExactCount;
+/// let mut bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 8]);
+/// assert!(transmute_many_aligned_mut_with_guard::<u32, _, _>(&mut bytes, &ExactCount(1)).is_err());
+/// ```
+pub fn transmute_many_aligned_mut_with_guard<'a, T: TriviallyTransmutable, G: DynGuard, A: AlignmentFor<T>>(bytes: &'a mut Aligned<A, [u8]>, guard: &G)
+                                                                                                          -> Result<&'a mut [T], GuardError> {
+    check_many_bytes::<T, _>(guard, &bytes.data)?;
+    Ok(transmute_many_permissive_aligned_mut(bytes))
+}
+
+/// View a statically aligned byte slice as a slice of a trivially transmutable type.
+///
+/// The resulting slice will have as many instances of a type as will fit, rounded down.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::{Aligned, Align16};
+/// # use safe_transmute::transmute_many_permissive_aligned;
+/// let bytes = Aligned::<Align16, _>::new([0x00, 0x01, 0x12]);
+/// assert_eq!(transmute_many_permissive_aligned::<u16, _>(&bytes), &[u16::from_be(0x0001)]);
+/// ```
+pub fn transmute_many_permissive_aligned<T: TriviallyTransmutable, A: AlignmentFor<T>>(bytes: &Aligned<A, [u8]>) -> &[T] {
+    unsafe { transmute_many_permissive_unchecked(&bytes.data) }
+}
+
+/// View a mutable, statically aligned byte slice as a mutable slice of a trivially transmutable type.
+///
+/// The resulting slice will have as many instances of a type as will fit, rounded down.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::align::{Aligned, Align8};
+/// # use safe_transmute::transmute_many_permissive_aligned_mut;
+/// let mut bytes = Aligned::<Align8, _>::new([0x00; 3]);
+/// transmute_many_permissive_aligned_mut::<u16, _>(&mut bytes)[0] = u16::from_be(0x1224);
+/// assert_eq!(bytes.data, [0x12, 0x24, 0x00]);
+/// ```
+pub fn transmute_many_permissive_aligned_mut<T: TriviallyTransmutable, A: AlignmentFor<T>>(bytes: &mut Aligned<A, [u8]>) -> &mut [T] {
+    unsafe { transmute_many_mut_unchecked::<T, PermissiveGuard>(&mut bytes.data).expect("permissive guard should never fail") }
+}
@@ -64,6 +64,8 @@
 
 
 use error::{ErrorReason, GuardError};
+use core::ops::RangeInclusive;
+use core::marker::PhantomData;
 use core::mem::size_of;
 
 
@@ -77,6 +79,152 @@ pub trait Guard {
     /// If the slice's size does not comply with this guard, an error
     /// which specifies the incompatibility is returned.
     fn check<T>(v: &[u8]) -> Result<(), GuardError>;
+
+    /// Check the size of the given byte slice against a particular type,
+    /// describing how it would be split up on success.
+    ///
+    /// # Errors
+    ///
+    /// If the slice's size does not comply with this guard, an error
+    /// which specifies the incompatibility is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use safe_transmute::guard::{PermissiveGuard, GuardOutcome, Guard};
+    /// assert_eq!(PermissiveGuard::check_outcome::<u16>(&[0x00; 5]),
+    ///            Ok(GuardOutcome {
+    ///                elements: 2,
+    ///                leftover_bytes: 1,
+    ///            }));
+    /// ```
+    fn check_outcome<T>(v: &[u8]) -> Result<GuardOutcome, GuardError> {
+        Self::check::<T>(v)?;
+        Ok(GuardOutcome::of::<T>(v))
+    }
+}
+
+
+/// The trait describes values which define boundary checking strategies,
+/// possibly depending on runtime state, unlike [`Guard`](trait.Guard.html).
+///
+/// Every `Guard` is also a `DynGuard`, as is `PhantomData<G>` for any `Guard` `G`.
+/// Each transmutation function taking a guard type has a `_with_guard` variant
+/// taking a `DynGuard` value instead.
+///
+/// This trait is meant for guards configured at runtime and passed by reference
+/// as a generic `&G`. As `check_bytes()` is generic over the target type,
+/// it cannot be used as a trait object: `dyn DynGuard` does not compile.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{ExactCount, DynGuard};
+/// let header_count = 2;
+/// assert_eq!(ExactCount(header_count).check_bytes::<u16>(&[0x00; 4]), Ok(()));
+/// assert_eq!(ExactCount(header_count).check_bytes::<u16>(&[0x00; 2]),
+///            Err(GuardError {
+///                required: 4,
+///                actual: 2,
+///                reason: ErrorReason::NotEnoughBytes,
+///            }));
+/// ```
+///
+/// ```compile_fail
+/// # use safe_transmute::guard::{ExactCount, DynGuard};
+/// let guard: &dyn DynGuard = &ExactCount(2);
+/// ```
+pub trait DynGuard {
+    /// Check the size of the given byte slice against a particular type.
+    ///
+    /// # Errors
+    ///
+    /// If the slice's size does not comply with this guard, an error
+    /// which specifies the incompatibility is returned.
+    fn check_bytes<T>(&self, v: &[u8]) -> Result<(), GuardError>;
+
+    /// Check the size of the given byte slice against a particular type,
+    /// describing how it would be split up on success.
+    ///
+    /// # Errors
+    ///
+    /// If the slice's size does not comply with this guard, an error
+    /// which specifies the incompatibility is returned.
+    fn check_bytes_outcome<T>(&self, v: &[u8]) -> Result<GuardOutcome, GuardError> {
+        self.check_bytes::<T>(v)?;
+        Ok(GuardOutcome::of::<T>(v))
+    }
+}
+
+impl<G: Guard> DynGuard for G {
+    #[inline]
+    fn check_bytes<T>(&self, bytes: &[u8]) -> Result<(), GuardError> {
+        G::check::<T>(bytes)
+    }
+}
+
+impl<G: Guard> DynGuard for PhantomData<G> {
+    #[inline]
+    fn check_bytes<T>(&self, bytes: &[u8]) -> Result<(), GuardError> {
+        G::check::<T>(bytes)
+    }
+}
+
+/// Check a byte slice about to be viewed or copied as many instances of `T` against the given guard.
+///
+/// Any amount of bytes could hold any amount of zero-sized values, so a zero-sized `T` is rejected
+/// with `ErrorReason::ZeroSizedTarget` before the guard is consulted.
+pub(crate) fn check_many_bytes<T, G: DynGuard>(guard: &G, bytes: &[u8]) -> Result<(), GuardError> {
+    if size_of::<T>() == 0 {
+        return Err(GuardError {
+            required: 0,
+            actual: bytes.len(),
+            reason: ErrorReason::ZeroSizedTarget,
+        });
+    }
+
+    guard.check_bytes::<T>(bytes)
+}
+
+
+/// How a byte slice which passed a guard is split up into instances of a type.
+///
+/// For zero-sized types, no instances are counted, and all bytes are left over.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::guard::GuardOutcome;
+/// assert_eq!(GuardOutcome::of::<u32>(&[0x00; 10]),
+///            GuardOutcome {
+///                elements: 2,
+///                leftover_bytes: 2,
+///            });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GuardOutcome {
+    /// The amount of whole instances of the type.
+    pub elements: usize,
+    /// The amount of trailing bytes which do not fill a whole instance.
+    pub leftover_bytes: usize,
+}
+
+impl GuardOutcome {
+    /// Describe how the given byte slice is split up into instances of `T`.
+    pub fn of<T>(bytes: &[u8]) -> GuardOutcome {
+        if size_of::<T>() == 0 {
+            GuardOutcome {
+                elements: 0,
+                leftover_bytes: bytes.len(),
+            }
+        } else {
+            GuardOutcome {
+                elements: bytes.len() / size_of::<T>(),
+                leftover_bytes: bytes.len() % size_of::<T>(),
+            }
+        }
+    }
 }
 
 
@@ -164,6 +312,10 @@ impl Guard for SingleManyGuard {
 
 /// Permissive guard: The resulting slice would have as many instances of a type as will
 /// fit, rounded down. Therefore, this guard will never yield an error.
+///
+/// The bytes left over are reported by [`Guard::check_outcome()`](trait.Guard.html#method.check_outcome),
+/// and returned by the `_with_tail` transmutation functions,
+/// such as [`transmute_many_with_tail()`](../fn.transmute_many_with_tail.html).
 pub struct PermissiveGuard;
 
 impl Guard for PermissiveGuard {
@@ -172,3 +324,354 @@ impl Guard for PermissiveGuard {
         Ok(())
     }
 }
+
+
+/// Not-empty guard: The byte slice must not be empty, and is otherwise unrestricted.
+///
+/// This is mostly useful in combination with other guards, see [`And`](struct.And.html).
+pub struct NotEmptyGuard;
+
+impl Guard for NotEmptyGuard {
+    #[inline]
+    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+        if bytes.is_empty() {
+            Err(GuardError {
+                required: 1,
+                actual: 0,
+                reason: ErrorReason::NotEnoughBytes,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+
+/// Conjunction of guards: The byte slice must satisfy both `A` and `B`.
+///
+/// `A` is checked first, and its error is returned if it fails.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{AllOrNothingGuard, NotEmptyGuard, Guard, And};
+/// type NonEmptyExact = And<NotEmptyGuard, AllOrNothingGuard>;
+///
+/// assert_eq!(NonEmptyExact::check::<u16>(&[0x00; 4]), Ok(()));
+/// assert_eq!(NonEmptyExact::check::<u16>(&[]),
+///            Err(GuardError {
+///                required: 1,
+///                actual: 0,
+///                reason: ErrorReason::NotEnoughBytes,
+///            }));
+/// assert_eq!(NonEmptyExact::check::<u16>(&[0x00; 3]),
+///            Err(GuardError {
+///                required: 2,
+///                actual: 3,
+///                reason: ErrorReason::InexactByteCount,
+///            }));
+/// ```
+pub struct And<A, B>(PhantomData<(A, B)>);
+
+impl<A: Guard, B: Guard> Guard for And<A, B> {
+    #[inline]
+    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+        A::check::<T>(bytes)?;
+        B::check::<T>(bytes)
+    }
+}
+
+
+/// Disjunction of guards: The byte slice must satisfy `A` or `B`.
+///
+/// `B` is only checked if `A` fails. If both fail, the error of `A` is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{SingleValueGuard, AllOrNothingGuard, Guard, Or};
+/// type OneOrNone = Or<SingleValueGuard, AllOrNothingGuard>;
+///
+/// assert_eq!(OneOrNone::check::<u32>(&[0x00; 4]), Ok(()));
+/// assert_eq!(OneOrNone::check::<u32>(&[0x00; 8]), Ok(()));
+/// assert_eq!(OneOrNone::check::<u32>(&[0x00; 3]),
+///            Err(GuardError {
+///                required: 4,
+///                actual: 3,
+///                reason: ErrorReason::InexactByteCount,
+///            }));
+/// ```
+pub struct Or<A, B>(PhantomData<(A, B)>);
+
+impl<A: Guard, B: Guard> Guard for Or<A, B> {
+    #[inline]
+    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+        A::check::<T>(bytes).or_else(|e| B::check::<T>(bytes).map_err(|_| e))
+    }
+}
+
+
+/// Exact count guard: The byte slice must have exactly enough bytes to fill `N` instances of a type.
+///
+/// Requires the `const_generics` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{ExactCountGuard, Guard};
+/// assert_eq!(ExactCountGuard::<2>::check::<u16>(&[0x00; 4]), Ok(()));
+/// assert_eq!(ExactCountGuard::<2>::check::<u16>(&[0x00; 5]),
+///            Err(GuardError {
+///                required: 4,
+///                actual: 5,
+///                reason: ErrorReason::TooManyBytes,
+///            }));
+/// ```
+#[cfg(feature = "const_generics")]
+pub struct ExactCountGuard<const N: usize>;
+
+#[cfg(feature = "const_generics")]
+impl<const N: usize> Guard for ExactCountGuard<N> {
+    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+        check_min_count::<T>(bytes, N)?;
+        check_max_count::<T>(bytes, N)
+    }
+}
+
+
+/// Minimum count guard: The byte slice must have at least enough bytes to fill `N` instances
+/// of a type, and extraneous data is ignored.
+///
+/// Requires the `const_generics` feature.
+#[cfg(feature = "const_generics")]
+pub struct MinCountGuard<const N: usize>;
+
+#[cfg(feature = "const_generics")]
+impl<const N: usize> Guard for MinCountGuard<N> {
+    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+        check_min_count::<T>(bytes, N)
+    }
+}
+
+
+/// Maximum count guard: The byte slice must have no more bytes than needed to fill `N` instances
+/// of a type. It can be empty, and may end in a partial instance as long as it stays within
+/// those `N * size_of::<T>()` bytes; a single byte past them is rejected.
+///
+/// Requires the `const_generics` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{MaxCountGuard, Guard};
+/// assert_eq!(MaxCountGuard::<2>::check::<u16>(&[0x00; 3]), Ok(()));
+/// assert_eq!(MaxCountGuard::<2>::check::<u16>(&[0x00; 5]),
+///            Err(GuardError {
+///                required: 4,
+///                actual: 5,
+///                reason: ErrorReason::TooManyBytes,
+///            }));
+/// ```
+#[cfg(feature = "const_generics")]
+pub struct MaxCountGuard<const N: usize>;
+
+#[cfg(feature = "const_generics")]
+impl<const N: usize> Guard for MaxCountGuard<N> {
+    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+        check_max_count::<T>(bytes, N)
+    }
+}
+
+
+/// Range count guard: The byte slice must have at least enough bytes to fill `MIN` instances
+/// of a type, and no more bytes than needed to fill `MAX` instances of it.
+///
+/// Requires the `const_generics` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{RangeCountGuard, Guard};
+/// assert_eq!(RangeCountGuard::<2, 64>::check::<u32>(&[0x00; 12]), Ok(()));
+/// assert_eq!(RangeCountGuard::<2, 64>::check::<u32>(&[0x00; 4]),
+///            Err(GuardError {
+///                required: 8,
+///                actual: 4,
+///                reason: ErrorReason::NotEnoughBytes,
+///            }));
+/// ```
+#[cfg(feature = "const_generics")]
+pub struct RangeCountGuard<const MIN: usize, const MAX: usize>;
+
+#[cfg(feature = "const_generics")]
+impl<const MIN: usize, const MAX: usize> Guard for RangeCountGuard<MIN, MAX> {
+    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+        check_min_count::<T>(bytes, MIN)?;
+        check_max_count::<T>(bytes, MAX)
+    }
+}
+
+
+/// Maximum bytes guard: The byte slice must not be longer than `N` bytes.
+///
+/// This is the static counterpart to [`MaxBytes`](struct.MaxBytes.html).
+///
+/// Requires the `const_generics` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::guard::{MaxBytesGuard, PedanticGuard, Guard, And};
+/// type Packet = And<PedanticGuard, MaxBytesGuard<4096>>;
+///
+/// assert!(Packet::check::<u32>(&[0x00; 4096]).is_ok());
+/// assert!(Packet::check::<u32>(&[0x00; 4100]).is_err());
+/// ```
+#[cfg(feature = "const_generics")]
+pub struct MaxBytesGuard<const N: usize>;
+
+#[cfg(feature = "const_generics")]
+impl<const N: usize> Guard for MaxBytesGuard<N> {
+    #[inline]
+    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+        check_max_count::<u8>(bytes, N)
+    }
+}
+
+
+/// Multiple-of guard: The amount of whole instances of a type in the byte slice
+/// must be a multiple of `N`, such as for RGB triplets or stereo frames.
+///
+/// A trailing partial instance is not counted; combine this with
+/// [`AllOrNothingGuard`](struct.AllOrNothingGuard.html) to reject it.
+/// For zero-sized types, the byte slice holds no whole instances.
+///
+/// Requires the `const_generics` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{MultipleOfGuard, Guard};
+/// assert_eq!(MultipleOfGuard::<3>::check::<u16>(&[0x00; 12]), Ok(()));
+/// assert_eq!(MultipleOfGuard::<3>::check::<u16>(&[0x00; 8]),
+///            Err(GuardError {
+///                required: 6,
+///                actual: 8,
+///                reason: ErrorReason::InexactByteCount,
+///            }));
+/// ```
+#[cfg(feature = "const_generics")]
+pub struct MultipleOfGuard<const N: usize>;
+
+#[cfg(feature = "const_generics")]
+impl<const N: usize> Guard for MultipleOfGuard<N> {
+    fn check<T>(bytes: &[u8]) -> Result<(), GuardError> {
+        let count = bytes.len().checked_div(size_of::<T>()).unwrap_or(0);
+        if count.checked_rem(N).unwrap_or(count) != 0 {
+            Err(GuardError {
+                required: size_of::<T>().saturating_mul(N),
+                actual: bytes.len(),
+                reason: ErrorReason::InexactByteCount,
+            })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+
+/// Exact count guard: The byte slice must have exactly enough bytes to fill the given amount of instances of a type.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExactCount(pub usize);
+
+impl DynGuard for ExactCount {
+    fn check_bytes<T>(&self, bytes: &[u8]) -> Result<(), GuardError> {
+        check_min_count::<T>(bytes, self.0)?;
+        check_max_count::<T>(bytes, self.0)
+    }
+}
+
+
+/// Maximum bytes guard: The byte slice must not be longer than the given amount of bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{MaxBytes, DynGuard};
+/// assert_eq!(MaxBytes(4).check_bytes::<u16>(&[0x00; 3]), Ok(()));
+/// assert_eq!(MaxBytes(4).check_bytes::<u16>(&[0x00; 6]),
+///            Err(GuardError {
+///                required: 4,
+///                actual: 6,
+///                reason: ErrorReason::TooManyBytes,
+///            }));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct MaxBytes(pub usize);
+
+impl DynGuard for MaxBytes {
+    fn check_bytes<T>(&self, bytes: &[u8]) -> Result<(), GuardError> {
+        check_max_count::<u8>(bytes, self.0)
+    }
+}
+
+
+/// Count range guard: The byte slice must have at least enough bytes to fill the range's start
+/// of instances of a type, and no more bytes than needed to fill the range's end of them.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError};
+/// # use safe_transmute::guard::{CountRange, DynGuard};
+/// assert_eq!(CountRange(2..=64).check_bytes::<u32>(&[0x00; 12]), Ok(()));
+/// assert_eq!(CountRange(2..=3).check_bytes::<u32>(&[0x00; 16]),
+///            Err(GuardError {
+///                required: 12,
+///                actual: 16,
+///                reason: ErrorReason::TooManyBytes,
+///            }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CountRange(pub RangeInclusive<usize>);
+
+impl DynGuard for CountRange {
+    fn check_bytes<T>(&self, bytes: &[u8]) -> Result<(), GuardError> {
+        check_min_count::<T>(bytes, *self.0.start())?;
+        check_max_count::<T>(bytes, *self.0.end())
+    }
+}
+
+
+fn check_min_count<T>(bytes: &[u8], count: usize) -> Result<(), GuardError> {
+    let required = size_of::<T>().saturating_mul(count);
+    if bytes.len() < required {
+        Err(GuardError {
+            required,
+            actual: bytes.len(),
+            reason: ErrorReason::NotEnoughBytes,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn check_max_count<T>(bytes: &[u8], count: usize) -> Result<(), GuardError> {
+    let allowed = size_of::<T>().saturating_mul(count);
+    if bytes.len() > allowed {
+        Err(GuardError {
+            required: allowed,
+            actual: bytes.len(),
+            reason: ErrorReason::TooManyBytes,
+        })
+    } else {
+        Ok(())
+    }
+}
@@ -37,6 +37,30 @@
 //! Note, though, that functions operating on items from `alloc` will also be disabled by this.
 //! If your no-`std` environment has an `alloc` implementation, you will have to reenable them by using `features = ["alloc"]`.
 //!
+//! # Zero-sized types
+//!
+//! Any amount of bytes could hold any amount of values of a zero-sized type,
+//! so transmuting bytes into many values of a zero-sized type fails with
+//! `ErrorReason::ZeroSizedTarget`, whatever the guard. A single value of a
+//! zero-sized type is read from an empty byte slice, and values of a zero-sized
+//! type have no bytes. Vectors, boxed and reference-counted slices of a
+//! zero-sized type keep their length when transmuted into another zero-sized type.
+//!
+//! ```
+//! # use safe_transmute::{TriviallyTransmutable, PermissiveGuard, ErrorReason, GuardError, Error, transmute_many, transmute_one};
+//! #[derive(Clone, Copy, Debug, PartialEq)]
+//! struct Marker;
+//! unsafe impl TriviallyTransmutable for Marker {}
+//!
+//! assert_eq!(transmute_one::<Marker>(&[]), Ok(Marker));
+//! assert_eq!(transmute_many::<Marker, PermissiveGuard>(&[0x00, 0x01, 0x02]),
+//!            Err(Error::Guard(GuardError {
+//!                required: 0,
+//!                actual: 3,
+//!                reason: ErrorReason::ZeroSizedTarget,
+//!            })));
+//! ```
+//!
 //! # Migrating
 //!
 //! If you've used `safe-transmute` before v0.11,
@@ -96,6 +120,22 @@
 //! # fn main() {}
 //! ```
 //!
+//! The same is available as the [`transmute_many_or_copy()`](fn.transmute_many_or_copy.html)
+//! family of functions, which return a `Cow<[T]>`, and can therefore be used regardless of
+//! the error type of the surrounding function.
+//!
+//! ```
+//! # #[cfg(feature = "alloc")]
+//! # {
+//! # use safe_transmute::{SingleManyGuard, transmute_many_or_copy};
+//! let bytes = &[0x00, 0x01, 0x12, 0x24, 0x00];
+//! let words = transmute_many_or_copy::<u16, SingleManyGuard>(bytes).unwrap();
+//!
+//! assert_eq!(*words,
+//!            [u16::from_be(0x0001), u16::from_be(0x1224)]);
+//! # }
+//! ```
+//!
 //! View all bytes as a series of `u16`s:
 //!
 //! ```
@@ -154,6 +194,7 @@
 
 
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
 
 #[cfg(feature = "std")]
@@ -164,32 +205,88 @@ pub extern crate alloc;
 
 mod full;
 
+pub mod ext;
 pub mod base;
 pub mod bool;
+pub mod cell;
 pub mod util;
 pub mod align;
 pub mod error;
 pub mod guard;
+pub mod atomic;
+pub mod uninit;
+pub mod zeroed;
+pub mod policy;
+pub mod builder;
 pub mod trivial;
 pub mod to_bytes;
 pub mod migration;
+#[cfg(feature = "alloc")]
+pub mod aligned_bytes;
 
 pub use self::full::{transmute_many_permissive_mut, transmute_many_pedantic_mut, transmute_many_permissive, transmute_many_pedantic, transmute_one_pedantic,
-                     transmute_many, transmute_many_mut, transmute_one};
+                     transmute_many_into, copy_from_bytes, transmute_many, transmute_many_mut, transmute_one};
+pub use self::full::{transmute_many_permissive_aligned_mut, transmute_many_permissive_aligned, transmute_many_aligned_mut, transmute_many_aligned};
+#[cfg(feature = "alloc")]
+pub use self::full::{extend_from_bytes_with_tail, transmute_box_slice, transmute_rc_slice, extend_from_bytes, transmute_vec, transmute_box};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use self::full::transmute_arc_slice;
+#[cfg(feature = "allocator_api")]
+pub use self::full::transmute_vec_in;
+#[cfg(feature = "alloc")]
+pub use self::full::{transmute_many_permissive_or_copy, transmute_many_or_copy_unchecked, transmute_many_pedantic_or_copy, transmute_many_or_copy,
+                     transmute_cow};
+pub use self::full::{transmute_many_mut_with_guard, transmute_many_into_with_guard, copy_from_bytes_with_guard, transmute_many_with_guard};
+pub use self::full::{transmute_many_aligned_mut_with_guard, transmute_many_aligned_with_guard};
+pub use self::full::{transmute_many_mut_with_tail_with_guard, transmute_many_with_tail_with_guard, transmute_many_mut_with_tail, transmute_many_with_tail};
+pub use self::full::{transmute_many_skip_unaligned_mut_with_guard, transmute_many_skip_unaligned_with_guard, transmute_many_skip_unaligned_mut,
+                     transmute_many_skip_unaligned};
+pub use self::full::{transmute_many_with_policy_with_guard, transmute_many_with_policy};
 #[cfg(feature = "alloc")]
-pub use self::full::transmute_vec;
+pub use self::full::{transmute_many_or_copy_unchecked_with_guard, extend_from_bytes_with_tail_with_guard, transmute_many_or_copy_with_guard,
+                     extend_from_bytes_with_guard, transmute_cow_with_guard};
 
 
-pub use self::guard::{SingleValueGuard, PermissiveGuard, SingleManyGuard, PedanticGuard, Guard};
+pub use self::guard::{SingleValueGuard, PermissiveGuard, SingleManyGuard, NotEmptyGuard, PedanticGuard, Guard};
+pub use self::guard::{GuardOutcome, CountRange, ExactCount, DynGuard, MaxBytes};
+#[cfg(feature = "const_generics")]
+pub use self::guard::{MultipleOfGuard, RangeCountGuard, ExactCountGuard, MaxBytesGuard, MaxCountGuard, MinCountGuard};
+pub use self::policy::{AlignmentPolicy, UnalignedIter};
+pub use self::builder::Transmute;
+pub use self::ext::{TransmuteExt, AsBytesExt};
+#[cfg(feature = "alloc")]
+pub use self::ext::TransmuteVecExt;
 pub use self::error::{UnalignedError, ErrorReason, GuardError, Error};
 #[cfg(feature = "alloc")]
-pub use self::error::IncompatibleVecTargetError;
+pub use self::error::{IncompatibleBoxTargetError, IncompatibleVecTargetError, IncompatibleRcTargetError};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use self::error::IncompatibleArcTargetError;
 pub use self::trivial::{TriviallyTransmutable, align_to_mut, align_to};
+pub use self::align::{AlignmentFor, AlignOf, Aligned};
+pub use self::zeroed::{Zeroable, zeroed};
+#[cfg(feature = "alloc")]
+pub use self::zeroed::{zeroed_box, zeroed_vec};
+pub use self::cell::{transmute_byte_cells_many_with_guard, transmute_cell_many_with_guard, transmute_byte_cells_many, transmute_cells_to_bytes,
+                     transmute_cell_many};
+pub use self::atomic::{transmute_atomic_many_with_guard, transmute_atomic_to_bytes_mut, transmute_atomic_many, snapshot_atomics, AtomicInteger,
+                       VolatileSlice};
+#[cfg(feature = "alloc")]
+pub use self::atomic::snapshot_atomics_vec;
+pub use self::uninit::{transmute_uninit_to_bytes_mut, transmute_uninit_many_mut};
+#[cfg(feature = "alloc")]
+pub use self::uninit::{assume_init_bytes_with_guard, spare_capacity_bytes_mut, assume_init_bytes};
+#[cfg(feature = "alloc")]
+pub use self::aligned_bytes::AlignedBytes;
 
 pub use self::to_bytes::{transmute_one_to_bytes_mut, transmute_one_to_bytes, transmute_to_bytes_mut, transmute_to_bytes};
+pub use self::to_bytes::{write_many_to_bytes_pedantic, write_to_bytes_pedantic, write_many_to_bytes, write_to_bytes};
 #[cfg(feature = "alloc")]
-pub use self::to_bytes::transmute_to_bytes_vec;
+pub use self::to_bytes::{transmute_to_bytes_box_slice, transmute_one_to_bytes_box, transmute_to_bytes_vec};
+#[cfg(feature = "allocator_api")]
+pub use self::to_bytes::transmute_to_bytes_vec_in;
 
 #[cfg(feature = "alloc")]
 pub use self::bool::{transmute_bool_vec_permissive, transmute_bool_vec_pedantic};
+#[cfg(feature = "allocator_api")]
+pub use self::bool::{transmute_bool_vec_permissive_in, transmute_bool_vec_pedantic_in};
 pub use self::bool::{transmute_bool_permissive, transmute_bool_pedantic};
@@ -0,0 +1,253 @@
+//! Alignment policies, selecting what to do with data not aligned for the target type.
+//!
+//! The functions in the crate root fail on misaligned data by default.
+//! An [`AlignmentPolicy`](trait.AlignmentPolicy.html) picks a different strategy
+//! at the type level, for use with
+//! [`transmute_many_with_policy()`](../fn.transmute_many_with_policy.html) or
+//! [`Transmute::on_unaligned()`](../builder/struct.Transmute.html#method.on_unaligned):
+//!
+//! - [`Strict`](struct.Strict.html) fails with `Error::Unaligned`, like
+//!   [`transmute_many()`](../fn.transmute_many.html);
+//! - [`CopyOnMisalign`](struct.CopyOnMisalign.html) copies misaligned data into a `Cow`,
+//!   like [`transmute_many_or_copy()`](../fn.transmute_many_or_copy.html);
+//! - [`SkipPrefix`](struct.SkipPrefix.html) drops the leading bytes up to the first aligned
+//!   position, like [`transmute_many_skip_unaligned()`](../fn.transmute_many_skip_unaligned.html);
+//! - [`Unaligned`](struct.Unaligned.html) reads each value separately through an
+//!   [`UnalignedIter`](struct.UnalignedIter.html), never borrowing the data as `T`s.
+//!
+//! # Examples
+//!
+//! A parser may settle on a policy once and use it throughout:
+//!
+//! ```
+//! # use safe_transmute::Transmute;
+//! # use safe_transmute::policy::Unaligned;
+//! const POLICY: Unaligned = Unaligned;
+//!
+//! let words = Transmute::<u16>::from(&[0x00, 0x01, 0x12, 0x24][..]).on_unaligned(POLICY).many().unwrap();
+//! assert!(words.eq([u16::from_be(0x0001), u16::from_be(0x1224)].iter().cloned()));
+//! ```
+
+
+use self::super::full::{transmute_many_skip_unaligned_with_guard, transmute_many_with_guard};
+#[cfg(feature = "alloc")]
+use self::super::full::transmute_many_or_copy_with_guard;
+use self::super::trivial::TriviallyTransmutable;
+use self::super::guard::{check_many_bytes, DynGuard};
+use core::iter::DoubleEndedIterator;
+use core::marker::PhantomData;
+use self::super::Error;
+use core::mem::size_of;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+use core::ptr;
+
+
+/// A strategy for transmuting byte slices which may not be aligned for `T`.
+///
+/// The lifetime `'a` is that of the byte slice,
+/// which the output of the policy may borrow from.
+pub trait AlignmentPolicy<'a, T: TriviallyTransmutable + 'a> {
+    /// The result of a successful transmutation.
+    type Output;
+
+    /// Transmute the given bytes into values of type `T`, checked against the given guard value.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the data does not comply with the policies of the given guard `guard`,
+    /// or if the policy refuses to handle its alignment.
+    fn apply<G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<Self::Output, Error<'a, u8, T>>;
+}
+
+
+/// Fail on data not aligned for `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{AlignOf, Aligned, Transmute, Error};
+/// # use safe_transmute::policy::Strict;
+/// let bytes = Aligned::<AlignOf<u16>, _>::new([0x00, 0x00, 0x01]);
+///
+/// assert_eq!(Transmute::<u16>::from(&bytes.data[..2]).on_unaligned(Strict).many(), Ok(&[0][..]));
+/// match Transmute::<u16>::from(&bytes.data[1..]).on_unaligned(Strict).many() {
+///     Err(Error::Unaligned(e)) => assert_eq!(e.offset, 1),
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Strict;
+
+impl<'a, T: TriviallyTransmutable + 'a> AlignmentPolicy<'a, T> for Strict {
+    type Output = &'a [T];
+
+    fn apply<G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<&'a [T], Error<'a, u8, T>> {
+        transmute_many_with_guard(bytes, guard)
+    }
+}
+
+
+/// Borrow data aligned for `T`, and copy it into a vector otherwise.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{AlignOf, Aligned, Transmute};
+/// # use safe_transmute::policy::CopyOnMisalign;
+/// # use std::borrow::Cow;
+/// let bytes = Aligned::<AlignOf<u16>, _>::new([0x00, 0x00, 0x01]);
+///
+/// let words = Transmute::<u16>::from(&bytes.data[1..]).on_unaligned(CopyOnMisalign).many().unwrap();
+/// assert_eq!(*words, [u16::from_be(0x0001)]);
+/// assert!(match words {
+///     Cow::Owned(_) => true,
+///     Cow::Borrowed(_) => false,
+/// });
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CopyOnMisalign;
+
+#[cfg(feature = "alloc")]
+impl<'a, T: TriviallyTransmutable + 'a> AlignmentPolicy<'a, T> for CopyOnMisalign {
+    type Output = Cow<'a, [T]>;
+
+    fn apply<G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<Cow<'a, [T]>, Error<'a, u8, T>> {
+        Ok(transmute_many_or_copy_with_guard(bytes, guard)?)
+    }
+}
+
+
+/// Drop the leading bytes up to the first position aligned for `T`,
+/// and any trailing bytes which do not fill a whole value.
+///
+/// The guard is applied to the bytes of the values only, between the dropped prefix and trailing bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PermissiveGuard, AlignOf, Aligned, Transmute};
+/// # use safe_transmute::policy::SkipPrefix;
+/// let bytes = Aligned::<AlignOf<u16>, _>::new([0xFF, 0x00, 0x01, 0x12, 0x24, 0xEE]);
+///
+/// assert_eq!(Transmute::<u16>::from(&bytes.data[1..]).guard::<PermissiveGuard>().on_unaligned(SkipPrefix).many(),
+///            Ok(&[u16::from_be(0x0112), u16::from_be(0x24EE)][..]));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SkipPrefix;
+
+impl<'a, T: TriviallyTransmutable + 'a> AlignmentPolicy<'a, T> for SkipPrefix {
+    type Output = &'a [T];
+
+    fn apply<G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<&'a [T], Error<'a, u8, T>> {
+        Ok(transmute_many_skip_unaligned_with_guard(bytes, guard)?.1)
+    }
+}
+
+
+/// Read the values one by one with unaligned loads, regardless of the alignment of the data.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{AlignOf, Aligned, Transmute};
+/// # use safe_transmute::policy::Unaligned;
+/// let bytes = Aligned::<AlignOf<u16>, _>::new([0xFF, 0x00, 0x01, 0x12, 0x24]);
+///
+/// let mut words = Transmute::<u16>::from(&bytes.data[1..]).on_unaligned(Unaligned).many().unwrap();
+/// assert_eq!(words.next(), Some(u16::from_be(0x0001)));
+/// assert_eq!(words.next(), Some(u16::from_be(0x1224)));
+/// assert_eq!(words.next(), None);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Unaligned;
+
+impl<'a, T: TriviallyTransmutable + 'a> AlignmentPolicy<'a, T> for Unaligned {
+    type Output = UnalignedIter<'a, T>;
+
+    fn apply<G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<UnalignedIter<'a, T>, Error<'a, u8, T>> {
+        check_many_bytes::<T, _>(guard, bytes)?;
+        Ok(UnalignedIter::new(bytes))
+    }
+}
+
+
+/// An iterator over the values of type `T` in a byte slice of any alignment,
+/// yielding copies read with unaligned loads.
+///
+/// Trailing bytes which do not fill a whole value are ignored.
+/// A byte slice holds no values of a zero-sized type.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::policy::UnalignedIter;
+/// let words = UnalignedIter::<u16>::new(&[0x00, 0x01, 0x12, 0x24, 0xEE]);
+/// assert_eq!(words.len(), 2);
+/// assert_eq!(words.rev().next(), Some(u16::from_be(0x1224)));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct UnalignedIter<'a, T: TriviallyTransmutable> {
+    bytes: &'a [u8],
+    marker: PhantomData<T>,
+}
+
+impl<'a, T: TriviallyTransmutable> UnalignedIter<'a, T> {
+    /// Iterate over the values of type `T` in the given bytes.
+    pub fn new(bytes: &'a [u8]) -> UnalignedIter<'a, T> {
+        let len = match size_of::<T>() {
+            0 => 0,
+            size => bytes.len() - bytes.len() % size,
+        };
+        UnalignedIter {
+            bytes: &bytes[..len],
+            marker: PhantomData,
+        }
+    }
+
+    /// The bytes of the values not yet yielded.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a, T: TriviallyTransmutable> Iterator for UnalignedIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let (value, rest) = self.bytes.split_at(size_of::<T>());
+        self.bytes = rest;
+        Some(unsafe { ptr::read_unaligned(value.as_ptr() as *const T) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: TriviallyTransmutable> DoubleEndedIterator for UnalignedIter<'a, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.bytes.is_empty() {
+            return None;
+        }
+
+        let (rest, value) = self.bytes.split_at(self.bytes.len() - size_of::<T>());
+        self.bytes = rest;
+        Some(unsafe { ptr::read_unaligned(value.as_ptr() as *const T) })
+    }
+}
+
+impl<'a, T: TriviallyTransmutable> ExactSizeIterator for UnalignedIter<'a, T> {
+    fn len(&self) -> usize {
+        match size_of::<T>() {
+            0 => 0,
+            size => self.bytes.len() / size,
+        }
+    }
+}
@@ -1,13 +1,27 @@
 //! Functions for transmutation *from* a concrete type *to* bytes.
 
 
+use self::super::guard::{SingleValueGuard, Guard};
+use self::super::error::{ErrorReason, GuardError};
 use self::super::TriviallyTransmutable;
 #[cfg(feature = "alloc")]
 use self::super::Error;
 use core::mem::size_of;
 use core::slice;
 #[cfg(feature = "alloc")]
+use self::super::error::IncompatibleBoxTargetError;
+#[cfg(feature = "alloc")]
+use core::mem::align_of;
+#[cfg(feature = "alloc")]
+use core::ptr;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
+#[cfg(feature = "allocator_api")]
+use self::super::error::IncompatibleVecTargetError;
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::Allocator;
 
 
 /// Transmute a single instance of an arbitrary type into a slice of its bytes.
@@ -412,6 +426,145 @@ pub fn transmute_to_bytes_mut<S: TriviallyTransmutable>(from: &mut [S]) -> &mut
     unsafe { transmute_to_bytes_many_unchecked_mut(from) }
 }
 
+/// Copy the bytes of a single instance of a trivially transmutable type into
+/// the start of a byte buffer, regardless of its alignment.
+///
+/// Bytes past the value are left untouched.
+///
+/// Returns the amount of bytes written.
+///
+/// # Errors
+///
+/// An error is returned if the buffer is shorter than the value.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::write_to_bytes;
+/// let mut buffer = [0xFF; 5];
+/// assert_eq!(write_to_bytes(&u32::from_be(0x0123_4567), &mut buffer[1..]), Ok(4));
+/// assert_eq!(buffer, [0xFF, 0x01, 0x23, 0x45, 0x67]);
+/// ```
+pub fn write_to_bytes<S: TriviallyTransmutable>(from: &S, dst: &mut [u8]) -> Result<usize, GuardError> {
+    write_bytes(transmute_one_to_bytes(from), dst, false)
+}
+
+/// Copy the bytes of a single instance of a trivially transmutable type into
+/// a byte buffer of exactly its size, regardless of the buffer's alignment.
+///
+/// This follows the semantics of [`SingleValueGuard`](../guard/struct.SingleValueGuard.html).
+///
+/// Returns the amount of bytes written.
+///
+/// # Errors
+///
+/// An error is returned if the buffer's length is not exactly the size of the value.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError, write_to_bytes_pedantic};
+/// let mut buffer = [0xFF; 5];
+/// assert_eq!(write_to_bytes_pedantic(&u16::from_be(0x0123), &mut buffer[..2]), Ok(2));
+/// assert_eq!(buffer, [0x01, 0x23, 0xFF, 0xFF, 0xFF]);
+///
+/// assert_eq!(write_to_bytes_pedantic(&0u16, &mut buffer),
+///            Err(GuardError {
+///                required: 2,
+///                actual: 5,
+///                reason: ErrorReason::InexactByteCount,
+///            }));
+/// ```
+pub fn write_to_bytes_pedantic<S: TriviallyTransmutable>(from: &S, dst: &mut [u8]) -> Result<usize, GuardError> {
+    SingleValueGuard::check::<S>(dst)?;
+    write_bytes(transmute_one_to_bytes(from), dst, true)
+}
+
+/// Copy the bytes of a slice of trivially transmutable values into the start
+/// of a byte buffer, regardless of its alignment.
+///
+/// Bytes past the values are left untouched.
+///
+/// Returns the amount of bytes written.
+///
+/// # Errors
+///
+/// An error is returned if the buffer is shorter than all of the values together.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::write_many_to_bytes;
+/// let mut buffer = [0xFF; 6];
+/// assert_eq!(write_many_to_bytes(&[u16::from_be(0x0123), u16::from_be(0x4567)], &mut buffer[1..]), Ok(4));
+/// assert_eq!(buffer, [0xFF, 0x01, 0x23, 0x45, 0x67, 0xFF]);
+/// ```
+pub fn write_many_to_bytes<S: TriviallyTransmutable>(from: &[S], dst: &mut [u8]) -> Result<usize, GuardError> {
+    write_bytes(transmute_to_bytes(from), dst, false)
+}
+
+/// Copy the bytes of a slice of trivially transmutable values into a byte
+/// buffer of exactly their size, regardless of the buffer's alignment.
+///
+/// Like with [`PedanticGuard`](../guard/struct.PedanticGuard.html), an empty
+/// slice of values is rejected.
+///
+/// Returns the amount of bytes written.
+///
+/// # Errors
+///
+/// An error is returned in one of the following situations:
+///
+/// - The slice of values is empty, reported as `NotEnoughBytes`.
+/// - The buffer is shorter than all of the values together, reported as `NotEnoughBytes`.
+/// - The buffer is longer than all of the values together, reported as `InexactByteCount`.
+///
+/// The `actual` length of the error is always that of the buffer.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{ErrorReason, GuardError, write_many_to_bytes_pedantic};
+/// let mut buffer = [0xFF; 4];
+/// assert_eq!(write_many_to_bytes_pedantic(&[u16::from_be(0x0123), u16::from_be(0x4567)], &mut buffer), Ok(4));
+/// assert_eq!(buffer, [0x01, 0x23, 0x45, 0x67]);
+///
+/// assert_eq!(write_many_to_bytes_pedantic::<u16>(&[], &mut buffer),
+///            Err(GuardError {
+///                required: 2,
+///                actual: 4,
+///                reason: ErrorReason::NotEnoughBytes,
+///            }));
+/// ```
+pub fn write_many_to_bytes_pedantic<S: TriviallyTransmutable>(from: &[S], dst: &mut [u8]) -> Result<usize, GuardError> {
+    if from.is_empty() {
+        return Err(GuardError {
+            required: size_of::<S>(),
+            actual: dst.len(),
+            reason: ErrorReason::NotEnoughBytes,
+        });
+    }
+
+    write_bytes(transmute_to_bytes(from), dst, true)
+}
+
+fn write_bytes(src: &[u8], dst: &mut [u8], exact: bool) -> Result<usize, GuardError> {
+    if dst.len() < src.len() || (exact && dst.len() != src.len()) {
+        return Err(GuardError {
+            required: src.len(),
+            actual: dst.len(),
+            reason: if dst.len() < src.len() {
+                ErrorReason::NotEnoughBytes
+            } else {
+                ErrorReason::InexactByteCount
+            },
+        });
+    }
+
+    dst[..src.len()].copy_from_slice(src);
+    Ok(src.len())
+}
+
 /// Transmute a slice of arbitrary types into a slice of their bytes.
 #[deprecated(since = "0.11.0", note = "use `transmute_to_bytes()` instead")]
 pub fn guarded_transmute_to_bytes_pod_many<S: TriviallyTransmutable>(from: &[S]) -> &[u8] {
@@ -442,3 +595,119 @@ pub fn guarded_transmute_to_bytes_pod_many<S: TriviallyTransmutable>(from: &[S])
 pub fn transmute_to_bytes_vec<S: TriviallyTransmutable>(from: Vec<S>) -> Result<Vec<u8>, Error<'static, S, u8>> {
     super::full::transmute_vec::<S, u8>(from)
 }
+
+/// Transmute a boxed slice of elements of an arbitrary type into a boxed slice
+/// of their bytes, using the same memory buffer as the former.
+///
+/// This is equivalent to calling [`full::transmute_box_slice()`](../fn.transmute_box_slice.html) where
+/// the target type is `u8`.
+///
+/// # Errors
+///
+/// An error is returned if the size or minimum memory alignment requirements
+/// are not the same between `S` and `u8`:
+///
+/// ```
+/// # /*
+/// std::mem::size_of::<S>() != 1
+/// std::mem::align_of::<S>() != 1
+/// # */
+/// ```
+///
+/// The only truly safe way of doing this is to create a transmuted slice
+/// view of the box or make a copy anyway.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_to_bytes_box_slice;
+/// assert_eq!(transmute_to_bytes_box_slice(vec![-0x01i8, 0x02i8].into_boxed_slice()),
+///            Ok(vec![0xFF, 0x02].into_boxed_slice()));
+/// assert!(transmute_to_bytes_box_slice(vec![0x0102u16].into_boxed_slice()).is_err());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_to_bytes_box_slice<S: TriviallyTransmutable>(from: Box<[S]>) -> Result<Box<[u8]>, Error<'static, S, u8>> {
+    super::full::transmute_box_slice::<S, u8>(from)
+}
+
+/// Transmute a single boxed instance of a trivially transmutable type into a
+/// boxed slice of its bytes, using the same memory buffer as the former.
+///
+/// # Errors
+///
+/// An error is returned if the minimum memory alignment requirements are not
+/// the same between `S` and `u8`:
+///
+/// ```
+/// # /*
+/// std::mem::align_of::<S>() != 1
+/// # */
+/// ```
+///
+/// The only truly safe way of doing this is to create a transmuted slice
+/// view of the box or make a copy anyway.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{Error, transmute_one_to_bytes_box};
+/// assert_eq!(transmute_one_to_bytes_box(Box::new([0x01u8, 0x02u8])),
+///            Ok(vec![0x01, 0x02].into_boxed_slice()));
+///
+/// let bytes = match transmute_one_to_bytes_box(Box::new(0x0102u16)) {
+///     Ok(bytes) => bytes,
+///     Err(Error::IncompatibleBoxTarget(e)) => e.copy(),
+///     Err(e) => panic!("Unexpected error: {}", e),
+/// };
+/// assert_eq!(*bytes, 0x0102u16.to_ne_bytes());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn transmute_one_to_bytes_box<S: TriviallyTransmutable>(from: Box<S>) -> Result<Box<[u8]>, Error<'static, S, u8>> {
+    let ptr = Box::into_raw(from);
+    if align_of::<S>() != align_of::<u8>() {
+        // A single value has the same layout as a one-element slice
+        let boxed = unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(ptr, 1)) };
+        return Err(IncompatibleBoxTargetError::new(boxed).into());
+    }
+
+    unsafe { Ok(Box::from_raw(ptr::slice_from_raw_parts_mut(ptr as *mut u8, size_of::<S>()))) }
+}
+
+/// Transmute a vector of elements of an arbitrary type in a custom allocator
+/// into a vector of their bytes, using the same memory buffer and allocator as the former.
+///
+/// This is equivalent to calling [`full::transmute_vec_in()`](../fn.transmute_vec_in.html) where
+/// the target type is `u8`.
+///
+/// Requires the `allocator_api` feature, and thus a nightly rustc.
+///
+/// # Errors
+///
+/// An error is returned if the size or minimum memory alignment requirements
+/// are not the same between `S` and `u8`:
+///
+/// ```
+/// # /*
+/// std::mem::size_of::<S>() != 1
+/// std::mem::align_of::<S>() != 1
+/// # */
+/// ```
+///
+/// The error hands the original vector back, and can make a copy in the same allocator.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(allocator_api)]
+/// # use safe_transmute::transmute_to_bytes_vec_in;
+/// # use std::alloc::Global;
+/// let mut words = Vec::new_in(Global);
+/// words.push(0x0102u16);
+///
+/// let bytes = transmute_to_bytes_vec_in(words).unwrap_or_else(|e| e.copy());
+/// assert_eq!(bytes, 0x0102u16.to_ne_bytes());
+/// ```
+#[cfg(feature = "allocator_api")]
+pub fn transmute_to_bytes_vec_in<S: TriviallyTransmutable, A: Allocator>(from: Vec<S, A>) -> Result<Vec<u8, A>, IncompatibleVecTargetError<S, u8, A>> {
+    super::full::transmute_vec_in::<S, u8, A>(from)
+}
@@ -15,11 +15,13 @@
 //! address flooring (e.g. ARM), but this is undefined behavior nonetheless.
 
 
-use self::super::guard::{PermissiveGuard, PedanticGuard, Guard};
-use self::super::base::{transmute_many, transmute_many_mut, from_bytes};
+use self::super::guard::{PermissiveGuard, PedanticGuard, DynGuard, Guard};
+use self::super::base::{transmute_many_mut_with_guard, transmute_many_with_guard, transmute_many_mut, transmute_many, from_bytes};
 #[cfg(feature = "alloc")]
 use self::super::base::transmute_vec;
+use core::marker::PhantomData;
 use self::super::Error;
+use core::slice;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
@@ -275,6 +277,35 @@ pub unsafe fn transmute_trivial_many<T: TriviallyTransmutable, G: Guard>(bytes:
     transmute_many::<T, G>(bytes)
 }
 
+/// Transmute a byte slice into a sequence of trivially transmutable values,
+/// checked against the given guard value.
+///
+/// This is [`transmute_trivial_many()`](fn.transmute_trivial_many.html) with a runtime guard,
+/// see [`DynGuard`](../guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `guard`.
+///
+/// # Safety
+///
+/// This function invokes undefined behavior if the data does not have a memory
+/// alignment compatible with `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::trivial::transmute_trivial_many_with_guard;
+/// # use safe_transmute::guard::ExactCount;
+/// unsafe {
+///     assert_eq!(transmute_trivial_many_with_guard::<i8, _>(&[0x00, 0xFF], &ExactCount(2)), Ok(&[0x00, -0x01][..]));
+/// }
+/// ```
+pub unsafe fn transmute_trivial_many_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<&'a [T], Error<'a, u8, T>> {
+    transmute_many_with_guard::<T, G>(bytes, guard)
+}
+
 /// Transmute a byte slice into a single instance of a trivially transmutable type.
 ///
 /// The byte slice must have exactly enough bytes to fill a single instance of a type.
@@ -312,6 +343,165 @@ pub unsafe fn transmute_trivial_many_mut<T: TriviallyTransmutable, G: Guard>(byt
     transmute_many_mut::<T, G>(bytes)
 }
 
+/// Transmute a mutable byte slice into a mutable sequence of trivially transmutable values,
+/// checked against the given guard value.
+///
+/// This is [`transmute_trivial_many_mut()`](fn.transmute_trivial_many_mut.html) with a runtime guard,
+/// see [`DynGuard`](../guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `guard`.
+///
+/// # Safety
+///
+/// This function invokes undefined behavior if the data does not have a memory
+/// alignment compatible with `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::trivial::transmute_trivial_many_mut_with_guard;
+/// # use safe_transmute::guard::ExactCount;
+/// unsafe {
+///     assert!(transmute_trivial_many_mut_with_guard::<i8, _>(&mut [0x00, 0xFF], &ExactCount(3)).is_err());
+/// }
+/// ```
+pub unsafe fn transmute_trivial_many_mut_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a mut [u8], guard: &G)
+                                                                                                -> Result<&'a mut [T], Error<'a, u8, T>> {
+    transmute_many_mut_with_guard::<T, G>(bytes, guard)
+}
+
+/// Transmute a byte slice into a sequence of trivially transmutable values,
+/// also returning the trailing bytes which do not fill a whole value.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Safety
+///
+/// This function invokes undefined behavior if the data does not have a memory
+/// alignment compatible with `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::trivial::transmute_trivial_many_with_tail;
+/// # use safe_transmute::PermissiveGuard;
+/// unsafe {
+///     assert_eq!(transmute_trivial_many_with_tail::<i8, PermissiveGuard>(&[0x00, 0xFF]), Ok((&[0x00, -0x01][..], &[][..])));
+/// }
+/// ```
+#[allow(clippy::type_complexity)]
+pub unsafe fn transmute_trivial_many_with_tail<T: TriviallyTransmutable, G: Guard>(bytes: &[u8]) -> Result<(&[T], &[u8]), Error<'_, u8, T>> {
+    transmute_trivial_many_with_tail_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a byte slice into a sequence of trivially transmutable values,
+/// also returning the trailing bytes which do not fill a whole value,
+/// checked against the given guard value.
+///
+/// This is [`transmute_trivial_many_with_tail()`](fn.transmute_trivial_many_with_tail.html) with a runtime guard,
+/// see [`DynGuard`](../guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `guard`.
+///
+/// # Safety
+///
+/// This function invokes undefined behavior if the data does not have a memory
+/// alignment compatible with `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::trivial::transmute_trivial_many_with_tail_with_guard;
+/// # use safe_transmute::guard::CountRange;
+/// unsafe {
+///     assert_eq!(transmute_trivial_many_with_tail_with_guard::<[i8; 2], _>(&[0x00, 0xFF, 0x01], &CountRange(1..=2)),
+///                Ok((&[[0x00, -0x01]][..], &[0x01][..])));
+/// }
+/// ```
+#[allow(clippy::type_complexity)]
+pub unsafe fn transmute_trivial_many_with_tail_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a [u8], guard: &G)
+                                                                                                     -> Result<(&'a [T], &'a [u8]), Error<'a, u8, T>> {
+    let outcome = guard.check_bytes_outcome::<T>(bytes)?;
+    let (head, tail) = bytes.split_at(bytes.len() - outcome.leftover_bytes);
+    Ok((slice::from_raw_parts(head.as_ptr() as *const T, outcome.elements), tail))
+}
+
+/// Transmute a mutable byte slice into a mutable sequence of trivially transmutable values,
+/// also returning the trailing bytes which do not fill a whole value.
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `G`.
+///
+/// # Safety
+///
+/// This function invokes undefined behavior if the data does not have a memory
+/// alignment compatible with `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::trivial::transmute_trivial_many_mut_with_tail;
+/// # use safe_transmute::PermissiveGuard;
+/// let mut bytes = [0x00, 0x00, 0x00];
+/// unsafe {
+///     let (signed, tail) = transmute_trivial_many_mut_with_tail::<[i8; 2], PermissiveGuard>(&mut bytes).unwrap();
+///     signed[0] = [-0x01, 0x01];
+///     tail[0] = 0x02;
+/// }
+/// assert_eq!(bytes, [0xFF, 0x01, 0x02]);
+/// ```
+#[allow(clippy::type_complexity)]
+pub unsafe fn transmute_trivial_many_mut_with_tail<T: TriviallyTransmutable, G: Guard>(bytes: &mut [u8])
+                                                                                       -> Result<(&mut [T], &mut [u8]), Error<'_, u8, T>> {
+    transmute_trivial_many_mut_with_tail_with_guard(bytes, &PhantomData::<G>)
+}
+
+/// Transmute a mutable byte slice into a mutable sequence of trivially transmutable values,
+/// also returning the trailing bytes which do not fill a whole value,
+/// checked against the given guard value.
+///
+/// This is [`transmute_trivial_many_mut_with_tail()`](fn.transmute_trivial_many_mut_with_tail.html) with a runtime guard,
+/// see [`DynGuard`](../guard/trait.DynGuard.html).
+///
+/// # Errors
+///
+/// An error is returned if the data does not comply with the policies of the
+/// given guard `guard`.
+///
+/// # Safety
+///
+/// This function invokes undefined behavior if the data does not have a memory
+/// alignment compatible with `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::trivial::transmute_trivial_many_mut_with_tail_with_guard;
+/// # use safe_transmute::guard::ExactCount;
+/// unsafe {
+///     assert!(transmute_trivial_many_mut_with_tail_with_guard::<[i8; 2], _>(&mut [0x00, 0xFF, 0x01], &ExactCount(2)).is_err());
+/// }
+/// ```
+#[allow(clippy::type_complexity)]
+pub unsafe fn transmute_trivial_many_mut_with_tail_with_guard<'a, T: TriviallyTransmutable, G: DynGuard>(bytes: &'a mut [u8], guard: &G)
+                                                                                                         -> Result<(&'a mut [T], &'a mut [u8]), Error<'a, u8, T>> {
+    let outcome = guard.check_bytes_outcome::<T>(bytes)?;
+    let split = bytes.len() - outcome.leftover_bytes;
+    let (head, tail) = bytes.split_at_mut(split);
+    Ok((slice::from_raw_parts_mut(head.as_mut_ptr() as *mut T, outcome.elements), tail))
+}
+
 /// View a byte slice as a slice of a trivially transmutable type.
 ///
 /// The resulting slice will have as many instances of a type as will fit, rounded down.
@@ -0,0 +1,159 @@
+//! Transmutation of uninitialised memory.
+//!
+//! These functions allow reading data (e.g. from an `io::Read`er) directly
+//! into typed buffers, without zeroing them first.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use self::super::align::check_alignment_mut;
+use self::super::error::UnalignedError;
+use self::super::base::transmuted_len;
+use core::mem::{MaybeUninit, size_of};
+use core::slice;
+#[cfg(feature = "alloc")]
+use self::super::error::GuardError;
+#[cfg(feature = "alloc")]
+use self::super::guard::{check_many_bytes, DynGuard, Guard};
+#[cfg(feature = "alloc")]
+use core::marker::PhantomData;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+/// View a mutable slice of uninitialised values as a mutable slice of uninitialised bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_uninit_to_bytes_mut;
+/// # use std::mem::MaybeUninit;
+/// let mut words = [MaybeUninit::<u32>::uninit(); 4];
+/// assert_eq!(transmute_uninit_to_bytes_mut(&mut words).len(), 16);
+/// ```
+pub fn transmute_uninit_to_bytes_mut<T: TriviallyTransmutable>(from: &mut [MaybeUninit<T>]) -> &mut [MaybeUninit<u8>] {
+    unsafe { slice::from_raw_parts_mut(from.as_mut_ptr() as *mut MaybeUninit<u8>, from.len() * size_of::<T>()) }
+}
+
+/// View a mutable slice of uninitialised bytes as a mutable slice of uninitialised values of the given type.
+///
+/// The resulting slice will have as many instances of a type as will fit, rounded down.
+///
+/// # Errors
+///
+/// An error is returned if the data does not have a memory alignment compatible with `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::transmute_uninit_many_mut;
+/// # use std::mem::MaybeUninit;
+/// let mut bytes = [MaybeUninit::<u8>::uninit(); 9];
+/// match transmute_uninit_many_mut::<u16>(&mut bytes) {
+///     Ok(words) => assert_eq!(words.len(), 4),
+///     Err(e) => assert_eq!(e.offset, 1),
+/// }
+/// ```
+pub fn transmute_uninit_many_mut<T: TriviallyTransmutable>(bytes: &mut [MaybeUninit<u8>])
+                                                           -> Result<&mut [MaybeUninit<T>], UnalignedError<'_, MaybeUninit<u8>, T>> {
+    let bytes = check_alignment_mut::<_, T>(bytes)?;
+    unsafe { Ok(slice::from_raw_parts_mut(bytes.as_mut_ptr() as *mut MaybeUninit<T>, transmuted_len::<u8, T>(bytes.len()))) }
+}
+
+/// View the spare capacity of a vector as a mutable slice of uninitialised bytes.
+///
+/// After some of these bytes have been written to, use
+/// [`assume_init_bytes()`](fn.assume_init_bytes.html) to extend the vector
+/// by the values they make up.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::spare_capacity_bytes_mut;
+/// let mut words = Vec::<u32>::with_capacity(4);
+/// words.push(1);
+/// assert_eq!(spare_capacity_bytes_mut(&mut words).len(), (words.capacity() - 1) * 4);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn spare_capacity_bytes_mut<T: TriviallyTransmutable>(vec: &mut Vec<T>) -> &mut [MaybeUninit<u8>] {
+    let spare = (vec.capacity() - vec.len()) * size_of::<T>();
+    unsafe { slice::from_raw_parts_mut(vec.as_mut_ptr().add(vec.len()) as *mut MaybeUninit<u8>, spare) }
+}
+
+/// Extend a vector by the values made up of the first `n` bytes of its spare capacity.
+///
+/// What happens to a trailing partial value depends on the guard `G`: for example,
+/// [`PermissiveGuard`](../guard/struct.PermissiveGuard.html) leaves it out of the vector,
+/// while [`PedanticGuard`](../guard/struct.PedanticGuard.html) rejects it.
+///
+/// Returns the amount of values the vector was extended by.
+///
+/// # Errors
+///
+/// An error is returned if the `n` bytes do not comply with the policies of the
+/// given guard `G`, in which case the vector is left unchanged.
+///
+/// # Panics
+///
+/// Panics if `n` is larger than the spare capacity in bytes.
+///
+/// # Safety
+///
+/// The first `n` bytes of the vector's spare capacity
+/// (as seen by [`spare_capacity_bytes_mut()`](fn.spare_capacity_bytes_mut.html))
+/// must have been initialised.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{PermissiveGuard, spare_capacity_bytes_mut, assume_init_bytes};
+/// # use std::mem::MaybeUninit;
+/// let mut words = Vec::<u16>::with_capacity(4);
+/// for (i, byte) in spare_capacity_bytes_mut(&mut words).iter_mut().take(5).enumerate() {
+///     *byte = MaybeUninit::new(i as u8);
+/// }
+///
+/// assert_eq!(unsafe { assume_init_bytes::<u16, PermissiveGuard>(&mut words, 5) }, Ok(2));
+/// assert_eq!(words, [u16::from_be(0x0001), u16::from_be(0x0203)]);
+/// ```
+#[cfg(feature = "alloc")]
+pub unsafe fn assume_init_bytes<T: TriviallyTransmutable, G: Guard>(vec: &mut Vec<T>, n: usize) -> Result<usize, GuardError> {
+    assume_init_bytes_with_guard(vec, n, &PhantomData::<G>)
+}
+
+/// Extend a vector by the values made up of the first `n` bytes of its spare capacity,
+/// checked against the given guard value.
+///
+/// This is [`assume_init_bytes()`](fn.assume_init_bytes.html) with a runtime guard,
+/// see [`DynGuard`](../guard/trait.DynGuard.html).
+///
+/// # Safety
+///
+/// See [`assume_init_bytes()`](fn.assume_init_bytes.html).
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::{spare_capacity_bytes_mut, assume_init_bytes_with_guard};
+/// # use safe_transmute::guard::ExactCount;
+/// # use std::mem::MaybeUninit;
+/// let mut words = Vec::<u16>::with_capacity(4);
+/// for byte in spare_capacity_bytes_mut(&mut words).iter_mut().take(4) {
+///     *byte = MaybeUninit::new(0xFF);
+/// }
+///
+/// assert!(unsafe { assume_init_bytes_with_guard(&mut words, 4, &ExactCount(3)) }.is_err());
+/// assert_eq!(unsafe { assume_init_bytes_with_guard(&mut words, 4, &ExactCount(2)) }, Ok(2));
+/// assert_eq!(words, [0xFFFF, 0xFFFF]);
+/// ```
+#[cfg(feature = "alloc")]
+pub unsafe fn assume_init_bytes_with_guard<T: TriviallyTransmutable, G: DynGuard>(vec: &mut Vec<T>, n: usize, guard: &G) -> Result<usize, GuardError> {
+    let spare = spare_capacity_bytes_mut(vec);
+    assert!(n <= spare.len(), "{} bytes assumed initialised, but only {} bytes of spare capacity", n, spare.len());
+
+    check_many_bytes::<T, _>(guard, slice::from_raw_parts(spare.as_ptr() as *const u8, n))?;
+
+    let count = transmuted_len::<u8, T>(n);
+    let len = vec.len();
+    vec.set_len(len + count);
+    Ok(count)
+}
@@ -15,6 +15,10 @@
 /// This expands into a single expression of type `Cow<[T]>`,
 /// where `T` is the target type.
 ///
+/// Outside of functions returning a compatible error type,
+/// or to avoid the macro altogether, see the
+/// [`transmute_many_or_copy()`](fn.transmute_many_or_copy.html) family of functions.
+///
 /// # Example
 ///
 /// ```
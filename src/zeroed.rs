@@ -0,0 +1,138 @@
+//! Construction of zero-initialised values.
+//!
+//! Any [`TriviallyTransmutable`](../trait.TriviallyTransmutable.html) type is
+//! valid when all of its bytes are zero, but so are a few other types, such as
+//! `Option<NonZeroU32>` or raw pointers, which are described by the
+//! [`Zeroable`](trait.Zeroable.html) trait.
+//!
+//! The allocating functions in this module request already zeroed memory from
+//! the allocator, which allows it to hand out fresh pages without touching them.
+
+
+use self::super::trivial::TriviallyTransmutable;
+use core::num::{NonZeroUsize, NonZeroIsize, NonZeroU128, NonZeroI128, NonZeroU16, NonZeroI16, NonZeroU32, NonZeroI32, NonZeroU64, NonZeroI64, NonZeroU8,
+                NonZeroI8};
+use core::mem;
+#[cfg(feature = "alloc")]
+use alloc::alloc::{handle_alloc_error, alloc_zeroed, Layout};
+#[cfg(feature = "alloc")]
+use core::mem::size_of;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+/// Type for which a value with all bytes set to zero is valid.
+///
+/// This is implemented for all [`TriviallyTransmutable`](../trait.TriviallyTransmutable.html)
+/// types, as well as for types which have some invalid bit patterns, but not zero,
+/// like `bool`, `char`, `Option<NonZero*>`, raw pointers and optional references.
+///
+/// Raw pointers are only covered when pointing to a sized type or a slice,
+/// since a zeroed pointer to a trait object would have a null vtable.
+///
+/// # Safety
+///
+/// It is only safe to implement `Zeroable` for a type `T` if a `T` with all of its bytes
+/// (including any padding) set to zero is a valid value of that type.
+pub unsafe trait Zeroable: Sized {}
+
+
+unsafe impl<T: TriviallyTransmutable> Zeroable for T {}
+
+unsafe impl Zeroable for bool {}
+unsafe impl Zeroable for char {}
+unsafe impl<T> Zeroable for *const T {}
+unsafe impl<T> Zeroable for *mut T {}
+unsafe impl<T> Zeroable for *const [T] {}
+unsafe impl<T> Zeroable for *mut [T] {}
+unsafe impl<T: ?Sized> Zeroable for Option<&T> {}
+unsafe impl<T: ?Sized> Zeroable for Option<&mut T> {}
+unsafe impl Zeroable for Option<NonZeroU8> {}
+unsafe impl Zeroable for Option<NonZeroI8> {}
+unsafe impl Zeroable for Option<NonZeroU16> {}
+unsafe impl Zeroable for Option<NonZeroI16> {}
+unsafe impl Zeroable for Option<NonZeroU32> {}
+unsafe impl Zeroable for Option<NonZeroI32> {}
+unsafe impl Zeroable for Option<NonZeroU64> {}
+unsafe impl Zeroable for Option<NonZeroI64> {}
+unsafe impl Zeroable for Option<NonZeroU128> {}
+unsafe impl Zeroable for Option<NonZeroI128> {}
+unsafe impl Zeroable for Option<NonZeroUsize> {}
+unsafe impl Zeroable for Option<NonZeroIsize> {}
+
+
+/// Create a value with all bytes set to zero.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::zeroed;
+/// # use std::num::NonZeroU32;
+/// assert_eq!(zeroed::<[u16; 4]>(), [0, 0, 0, 0]);
+/// assert_eq!(zeroed::<Option<NonZeroU32>>(), None);
+/// ```
+pub fn zeroed<T: Zeroable>() -> T {
+    unsafe { mem::zeroed() }
+}
+
+/// Create a boxed value with all bytes set to zero.
+///
+/// The value is allocated zeroed in place, so this is suitable for types too large for the stack.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::zeroed_box;
+/// let table = zeroed_box::<[[u64; 32]; 32]>();
+/// assert!(table.iter().flat_map(|row| row.iter()).all(|&x| x == 0));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn zeroed_box<T: Zeroable>() -> Box<T> {
+    if size_of::<T>() == 0 {
+        return Box::new(zeroed());
+    }
+
+    let layout = Layout::new::<T>();
+    unsafe {
+        let ptr = alloc_zeroed(layout);
+        if ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+        Box::from_raw(ptr as *mut T)
+    }
+}
+
+/// Create a vector of `len` values with all bytes set to zero.
+///
+/// Unlike `vec![0u8; n]` followed by a transmutation, the vector is allocated
+/// with the alignment of `T`, so this cannot fail.
+///
+/// # Panics
+///
+/// Panics if the allocation size overflows.
+///
+/// # Examples
+///
+/// ```
+/// # use safe_transmute::zeroed_vec;
+/// let words = zeroed_vec::<u32>(1024);
+/// assert_eq!(words.len(), 1024);
+/// assert!(words.iter().all(|&x| x == 0));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn zeroed_vec<T: Zeroable>(len: usize) -> Vec<T> {
+    if len == 0 || size_of::<T>() == 0 {
+        return (0..len).map(|_| zeroed()).collect();
+    }
+
+    let layout = Layout::array::<T>(len).expect("capacity overflow");
+    unsafe {
+        let ptr = alloc_zeroed(layout);
+        if ptr.is_null() {
+            handle_alloc_error(layout);
+        }
+        Vec::from_raw_parts(ptr as *mut T, len, len)
+    }
+}
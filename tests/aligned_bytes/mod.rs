@@ -0,0 +1,101 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{AlignedBytes, transmute_many_pedantic_mut, transmute_many_pedantic, transmute_to_bytes};
+use core::mem::align_of;
+use alloc::vec::Vec;
+
+
+fn check_alignment<T>(buffer: &AlignedBytes<T>) {
+    assert_eq!((buffer.as_bytes().as_ptr() as usize) % align_of::<T>(), 0);
+}
+
+
+#[test]
+fn empty() {
+    let mut buffer = AlignedBytes::<u64>::new();
+    assert_eq!(buffer.capacity(), 0);
+    assert!(buffer.is_empty());
+    assert_eq!(buffer.as_bytes(), &[]);
+    assert_eq!(buffer.as_slice(), &[]);
+    assert_eq!(buffer.as_mut_slice(), &mut []);
+    check_alignment(&buffer);
+}
+
+#[test]
+fn from_slice() {
+    let words: &[u64] = &[0x0102_0304_0506_0708, 0x090A_0B0C_0D0E_0F10];
+    let bytes = transmute_to_bytes(words);
+
+    for offset in 0..8 {
+        let buffer = AlignedBytes::<u64>::from_slice(&bytes[offset..offset + 8]);
+        check_alignment(&buffer);
+        assert_eq!(buffer.as_bytes(), &bytes[offset..offset + 8]);
+        assert_eq!(transmute_to_bytes(buffer.as_slice()), &bytes[offset..offset + 8]);
+        assert_eq!(transmute_many_pedantic::<u64>(buffer.as_bytes()), Ok(buffer.as_slice()));
+    }
+}
+
+#[test]
+fn push_bytes() {
+    let mut buffer = AlignedBytes::<u32>::with_capacity(1);
+    for i in 0..100u8 {
+        buffer.push_bytes(&[i]);
+        check_alignment(&buffer);
+        assert_eq!(buffer.len(), i as usize + 1);
+        assert_eq!(buffer.as_slice().len(), (i as usize + 1) / 4);
+    }
+    assert!(buffer.capacity() >= 100);
+    assert_eq!(buffer.as_bytes(), &(0..100).collect::<Vec<u8>>()[..]);
+}
+
+#[test]
+fn resize() {
+    let mut buffer = AlignedBytes::<u16>::from_slice(&[0x01, 0x02, 0x03]);
+    buffer.resize(64, 0xAA);
+    check_alignment(&buffer);
+    assert_eq!(&buffer.as_bytes()[..4], &[0x01, 0x02, 0x03, 0xAA]);
+    assert!(buffer.as_bytes()[3..].iter().all(|&b| b == 0xAA));
+    assert_eq!(buffer.as_slice().len(), 32);
+
+    buffer.resize(1, 0x00);
+    assert_eq!(buffer.as_bytes(), &[0x01]);
+    assert_eq!(buffer.as_slice(), &[]);
+
+    buffer.clear();
+    assert!(buffer.is_empty());
+    assert!(buffer.capacity() >= 64);
+}
+
+#[test]
+fn mutable_views() {
+    let mut buffer = AlignedBytes::<u32>::from_slice(&[0; 9]);
+    buffer.as_mut_slice()[1] = 0x0102_0304;
+    buffer.as_mut_bytes()[8] = 0xFF;
+    transmute_many_pedantic_mut::<u32>(&mut buffer.as_mut_bytes()[..8]).unwrap()[0] = 0x0506_0708;
+
+    assert_eq!(buffer.as_slice(), &[0x0506_0708, 0x0102_0304]);
+    assert_eq!(buffer.as_bytes()[8], 0xFF);
+}
+
+#[test]
+fn clone_eq() {
+    let buffer = AlignedBytes::<u64>::from_slice(&[1, 2, 3, 4, 5]);
+    let copy = buffer.clone();
+    check_alignment(&copy);
+    assert_eq!(buffer, copy);
+    assert!(buffer.as_bytes().as_ptr() != copy.as_bytes().as_ptr());
+}
+
+#[test]
+fn zero_sized() {
+    let buffer = AlignedBytes::<[u64; 0]>::from_slice(&[1, 2, 3]);
+    check_alignment(&buffer);
+    assert_eq!(buffer.as_bytes(), &[1, 2, 3]);
+}
+
+#[test]
+fn send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<AlignedBytes<u32>>();
+}
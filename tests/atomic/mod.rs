@@ -0,0 +1,164 @@
+use safe_transmute::{transmute_atomic_to_bytes_mut, transmute_atomic_many, snapshot_atomics, SingleManyGuard, PedanticGuard, VolatileSlice, ErrorReason,
+                     GuardError, AlignOf, Aligned, Error};
+use core::sync::atomic::{AtomicU64, AtomicU32, AtomicU16, AtomicU8, Ordering};
+#[cfg(feature = "alloc")]
+use safe_transmute::snapshot_atomics_vec;
+
+
+#[test]
+fn too_short() {
+    let mut bytes = Aligned::<AlignOf<AtomicU64>, _>::new([0x00; 7]);
+    match transmute_atomic_many::<AtomicU64, SingleManyGuard>(&mut bytes.data) {
+        Err(Error::Guard(e)) => {
+            assert_eq!(e,
+                       GuardError {
+                           required: 64 / 8,
+                           actual: 7,
+                           reason: ErrorReason::NotEnoughBytes,
+                       })
+        }
+        Err(e) => panic!("Expected `GuardError`, got {:?}", e),
+        Ok(_) => panic!("Expected `GuardError`"),
+    }
+}
+
+#[test]
+fn unaligned() {
+    let mut bytes = Aligned::<AlignOf<AtomicU32>, _>::new([0x00; 9]);
+    match transmute_atomic_many::<AtomicU32, PedanticGuard>(&mut bytes.data[1..]) {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        Err(e) => panic!("Expected `UnalignedError`, got {:?}", e),
+        Ok(_) => panic!("Expected `UnalignedError`"),
+    }
+}
+
+#[test]
+fn shared_writes() {
+    let mut bytes = Aligned::<AlignOf<AtomicU16>, _>::new([0x00; 6]);
+    {
+        let words = transmute_atomic_many::<AtomicU16, PedanticGuard>(&mut bytes.data).unwrap();
+        assert_eq!(words.len(), 3);
+        words[0].store(0x0102, Ordering::Relaxed);
+        words[2].fetch_or(0x0304, Ordering::Relaxed);
+
+        let mut snapshot = [0u16; 4];
+        assert_eq!(snapshot_atomics(words, &mut snapshot), 3);
+        assert_eq!(snapshot, [0x0102, 0x0000, 0x0304, 0x0000]);
+    }
+    assert_eq!(bytes.data[..2], 0x0102u16.to_ne_bytes());
+    assert_eq!(bytes.data[4..], 0x0304u16.to_ne_bytes());
+}
+
+#[test]
+fn to_bytes() {
+    let mut atomics = [AtomicU8::new(1), AtomicU8::new(2)];
+    transmute_atomic_to_bytes_mut(&mut atomics)[1] = 3;
+    assert_eq!(atomics[0].load(Ordering::Relaxed), 1);
+    assert_eq!(atomics[1].load(Ordering::Relaxed), 3);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn snapshot_vec() {
+    let atomics = [AtomicU32::new(0x0102_0304), AtomicU32::new(0x0506_0708)];
+    assert_eq!(snapshot_atomics_vec(&atomics), [0x0102_0304, 0x0506_0708]);
+    assert!(snapshot_atomics_vec::<AtomicU32>(&[]).is_empty());
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn threads() {
+    use std::thread;
+
+    let bytes = Box::leak(Box::new(Aligned::<AlignOf<AtomicU32>, _>::new([0x00; 16])));
+    let counters: &'static [AtomicU32] = transmute_atomic_many::<AtomicU32, PedanticGuard>(&mut bytes.data).unwrap();
+
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            thread::spawn(move || for counter in counters {
+                for _ in 0..1000 {
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().unwrap();
+    }
+
+    assert_eq!(snapshot_atomics_vec(counters), [4000; 4]);
+}
+
+#[test]
+fn volatile() {
+    let mut registers = [0u16; 3];
+    {
+        let volatile = VolatileSlice::from_mut(&mut registers);
+        assert_eq!(volatile.len(), 3);
+        assert!(!volatile.is_empty());
+
+        volatile.copy_from_slice(&[1, 2, 3]);
+        volatile.write(1, 0xFFFF);
+        assert_eq!(volatile.read(0), 1);
+        assert_eq!(volatile.read(1), 0xFFFF);
+
+        let mut snapshot = [0u16; 3];
+        volatile.copy_to_slice(&mut snapshot);
+        assert_eq!(snapshot, [1, 0xFFFF, 3]);
+    }
+    assert_eq!(registers, [1, 0xFFFF, 3]);
+}
+
+#[test]
+#[should_panic]
+fn volatile_out_of_bounds() {
+    let mut registers = [0u16; 3];
+    VolatileSlice::from_mut(&mut registers).read(3);
+}
+
+#[test]
+#[cfg(all(target_os = "linux", target_pointer_width = "64"))]
+#[cfg_attr(miri, ignore)]
+fn shared_mapping() {
+    use core::{slice, ptr};
+
+    extern "C" {
+        fn memfd_create(name: *const u8, flags: u32) -> i32;
+        fn ftruncate(fd: i32, length: i64) -> i32;
+        fn mmap(addr: *mut u8, length: usize, prot: i32, flags: i32, fd: i32, offset: i64) -> *mut u8;
+        fn munmap(addr: *mut u8, length: usize) -> i32;
+        fn close(fd: i32) -> i32;
+    }
+
+    const PROT_READ: i32 = 0x1;
+    const PROT_WRITE: i32 = 0x2;
+    const MAP_SHARED: i32 = 0x1;
+    const LEN: usize = 4096;
+
+    unsafe {
+        let fd = memfd_create(b"safe_transmute\0".as_ptr(), 0);
+        assert!(fd >= 0);
+        assert_eq!(ftruncate(fd, LEN as i64), 0);
+
+        let first = mmap(ptr::null_mut(), LEN, PROT_READ | PROT_WRITE, MAP_SHARED, fd, 0);
+        let second = mmap(ptr::null_mut(), LEN, PROT_READ | PROT_WRITE, MAP_SHARED, fd, 0);
+        assert!(first as isize != -1 && second as isize != -1);
+        assert!(first != second);
+
+        {
+            let counters = transmute_atomic_many::<AtomicU32, PedanticGuard>(slice::from_raw_parts_mut(first, LEN)).unwrap();
+            let volatile = VolatileSlice::new(second as *mut u32, LEN / 4);
+
+            counters[1].store(0x0102_0304, Ordering::SeqCst);
+            assert_eq!(volatile.read(1), 0x0102_0304);
+
+            volatile.write(2, 0xAABB_CCDD);
+            assert_eq!(counters[2].load(Ordering::SeqCst), 0xAABB_CCDD);
+            assert_eq!(counters[0].load(Ordering::SeqCst), 0);
+        }
+
+        assert_eq!(munmap(first, LEN), 0);
+        assert_eq!(munmap(second, LEN), 0);
+        assert_eq!(close(fd), 0);
+    }
+}
@@ -2,3 +2,4 @@ mod vec_permissive;
 mod vec_pedantic;
 mod permissive;
 mod pedantic;
+mod vec_in;
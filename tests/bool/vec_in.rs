@@ -0,0 +1,43 @@
+#![cfg(feature = "allocator_api")]
+
+
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_bool_vec_permissive_in, transmute_bool_vec_pedantic_in};
+use self::super::super::CountingAlloc;
+use alloc::vec::Vec;
+use core::cell::Cell;
+
+
+#[test]
+fn permissive() {
+    let live = Cell::new(0);
+    {
+        let mut bytes = Vec::new_in(CountingAlloc(&live));
+        bytes.extend_from_slice(&[0x00, 0x01, 0x01]);
+
+        assert_eq!(transmute_bool_vec_permissive_in(bytes).unwrap(), [false, true, true]);
+        assert_eq!(transmute_bool_vec_permissive_in(Vec::new_in(CountingAlloc(&live))).unwrap(), []);
+    }
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn pedantic() {
+    let live = Cell::new(0);
+    {
+        let mut bytes = Vec::new_in(CountingAlloc(&live));
+        bytes.extend_from_slice(&[0x01, 0x00]);
+        assert_eq!(transmute_bool_vec_pedantic_in(bytes).unwrap(), [true, false]);
+
+        assert_eq!(transmute_bool_vec_pedantic_in(Vec::new_in(CountingAlloc(&live))),
+                   Err(Error::Guard(GuardError {
+                       required: 1,
+                       actual: 0,
+                       reason: ErrorReason::NotEnoughBytes,
+                   })));
+
+        let mut bytes = Vec::new_in(CountingAlloc(&live));
+        bytes.extend_from_slice(&[0x00, 0x02]);
+        assert_eq!(transmute_bool_vec_pedantic_in(bytes), Err(Error::InvalidValue));
+    }
+    assert_eq!(live.get(), 0);
+}
@@ -0,0 +1,102 @@
+use safe_transmute::{transmute_many_pedantic, TriviallyTransmutable, SingleManyGuard, PermissiveGuard, ErrorReason, GuardError, Transmute, DynGuard, AlignOf,
+                     Aligned, Error};
+use safe_transmute::policy::{AlignmentPolicy, SkipPrefix, Unaligned};
+use core::mem::size_of;
+#[cfg(feature = "alloc")]
+use safe_transmute::policy::CopyOnMisalign;
+
+
+#[test]
+fn defaults() {
+    let bytes = Aligned::<AlignOf<u16>, _>::new([0x01, 0x00, 0x02, 0x00, 0x03]);
+    for data in &[&bytes.data[..], &bytes.data[..4], &bytes.data[1..]] {
+        assert_eq!(Transmute::<u16>::from(*data).many(), transmute_many_pedantic::<u16>(data));
+    }
+}
+
+#[test]
+fn guard() {
+    let bytes = Aligned::<AlignOf<u16>, _>::new([0x01, 0x00, 0x02, 0x00, 0x03]);
+    assert_eq!(Transmute::<u16>::from(&bytes.data[..]).guard::<PermissiveGuard>().many(),
+               Ok(&[u16::from_le(1), u16::from_le(2)][..]));
+    assert_eq!(Transmute::<u16>::from(&bytes.data[..1]).guard::<SingleManyGuard>().many(),
+               Err(Error::Guard(GuardError {
+                   required: 16 / 8,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn on_unaligned() {
+    let bytes = Aligned::<AlignOf<u16>, _>::new([0xFF, 0x01, 0x00, 0x02, 0x00, 0x03]);
+    assert_eq!(Transmute::<u16>::from(&bytes.data[1..]).on_unaligned(SkipPrefix).many(),
+               Ok(&[u16::from_le(0x0200), u16::from_le(0x0300)][..]));
+    assert!(Transmute::<u16>::from(&bytes.data[1..5])
+        .on_unaligned(Unaligned)
+        .many()
+        .unwrap()
+        .eq([u16::from_le(1), u16::from_le(2)].iter().cloned()));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn copy_on_misalign() {
+    let bytes = Aligned::<AlignOf<u16>, _>::new([0xFF, 0x01, 0x00, 0x02, 0x00]);
+    assert_eq!(*Transmute::<u16>::from(&bytes.data[1..]).on_unaligned(CopyOnMisalign).many().unwrap(),
+               [u16::from_le(1), u16::from_le(2)]);
+}
+
+#[test]
+fn validate() {
+    let bytes = Aligned::<AlignOf<u16>, _>::new([0xFF, 0x01, 0x00, 0x02, 0x00]);
+    let small = |&w: &u16| u16::from_le(w) < 2;
+    assert_eq!(Transmute::<u16>::from(&bytes.data[1..3]).on_unaligned(Unaligned).validate(small).many().map(|words| words.len()),
+               Ok(1));
+    assert_eq!(Transmute::<u16>::from(&bytes.data[1..]).on_unaligned(Unaligned).validate(small).many().err(),
+               Some(Error::InvalidValue));
+    assert_eq!(Transmute::<u16>::from(&bytes.data[1..]).on_unaligned(Unaligned).validate(small).validate(|_| true).many().map(|words| words.len()),
+               Ok(2));
+}
+
+#[test]
+fn custom_policy() {
+    struct Count;
+
+    impl<'a, T: TriviallyTransmutable + 'a> AlignmentPolicy<'a, T> for Count {
+        type Output = usize;
+
+        fn apply<G: DynGuard>(bytes: &'a [u8], guard: &G) -> Result<usize, Error<'a, u8, T>> {
+            guard.check_bytes::<T>(bytes)?;
+            Ok(bytes.len() / size_of::<T>())
+        }
+    }
+
+    assert_eq!(Transmute::<u16>::from(&[0x00; 5][..]).guard::<PermissiveGuard>().on_unaligned(Count).many(), Ok(2));
+}
+
+#[test]
+fn validation_after_guard() {
+    assert_eq!(Transmute::<u8>::from(&[][..]).validate(|_| false).many(),
+               Err(Error::Guard(GuardError {
+                   required: 1,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn copy_without_guard_traits() {
+    let bytes = Aligned::<AlignOf<u16>, _>::new([0x01, 0x00, 0x02, 0x00]);
+    let builder = Transmute::<u16>::from(&bytes.data[..]).guard::<SingleManyGuard>().on_unaligned(Unaligned);
+    let copy = builder;
+    assert_eq!(builder.many().map(|words| words.len()), Ok(2));
+    assert_eq!(copy.many().map(|words| words.len()), Ok(2));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn debug_without_guard_traits() {
+    let builder = Transmute::<u16>::from(&[0x01, 0x00][..]).guard::<SingleManyGuard>();
+    assert!(format!("{:?}", builder).starts_with("Transmute { bytes: [1, 0], guard: PhantomData"));
+}
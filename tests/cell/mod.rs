@@ -0,0 +1,74 @@
+use safe_transmute::{transmute_byte_cells_many, transmute_cells_to_bytes, transmute_cell_many, SingleManyGuard, PedanticGuard, ErrorReason, GuardError,
+                     AlignOf, Aligned, Error};
+use core::cell::Cell;
+
+
+#[test]
+fn too_short() {
+    let mut buffer = Aligned::<AlignOf<u32>, _>::new([0x00; 3]);
+    match transmute_cell_many::<u32, SingleManyGuard>(Cell::from_mut(&mut buffer.data[..])) {
+        Err(Error::Guard(e)) => {
+            assert_eq!(e,
+                       GuardError {
+                           required: 32 / 8,
+                           actual: 3,
+                           reason: ErrorReason::NotEnoughBytes,
+                       })
+        }
+        Err(e) => panic!("Expected `GuardError`, got {:?}", e),
+        Ok(_) => panic!("Expected `GuardError`"),
+    }
+}
+
+#[test]
+fn inexact() {
+    let mut buffer = Aligned::<AlignOf<u32>, _>::new([0x00; 6]);
+    match transmute_cell_many::<u32, PedanticGuard>(Cell::from_mut(&mut buffer.data[..])) {
+        Err(Error::Guard(e)) => assert_eq!(e.reason, ErrorReason::InexactByteCount),
+        Err(e) => panic!("Expected `GuardError`, got {:?}", e),
+        Ok(_) => panic!("Expected `GuardError`"),
+    }
+}
+
+#[test]
+fn unaligned() {
+    let mut buffer = Aligned::<AlignOf<u32>, _>::new([0x00; 9]);
+    let bytes = Cell::from_mut(&mut buffer.data[..]).as_slice_of_cells();
+    match transmute_byte_cells_many::<u32, PedanticGuard>(&bytes[1..]) {
+        Err(Error::Unaligned(e)) => {
+            assert_eq!(e.offset, 3);
+            assert_eq!(e.source.len(), 8);
+        }
+        Err(e) => panic!("Expected `UnalignedError`, got {:?}", e),
+        Ok(_) => panic!("Expected `UnalignedError`"),
+    }
+}
+
+#[test]
+fn aliasing_views() {
+    let mut buffer = Aligned::<AlignOf<u64>, _>::new([0x00; 8]);
+    {
+        let bytes = Cell::from_mut(&mut buffer.data[..]);
+        let quad = transmute_cell_many::<u64, PedanticGuard>(bytes).unwrap();
+        let words = transmute_cell_many::<u16, PedanticGuard>(bytes).unwrap();
+        let byte_cells = transmute_cells_to_bytes(words);
+        assert_eq!(quad.len(), 1);
+        assert_eq!(words.len(), 4);
+        assert_eq!(byte_cells.len(), 8);
+
+        quad[0].set(u64::from_be(0x0102_0304_0506_0708));
+        assert_eq!(words[1].get(), u16::from_be(0x0304));
+
+        words[3].set(0xFFFF);
+        byte_cells[0].set(0xAA);
+        assert_eq!(quad[0].get(), u64::from_be(0xAA02_0304_0506_FFFF));
+    }
+    assert_eq!(buffer.data, [0xAA, 0x02, 0x03, 0x04, 0x05, 0x06, 0xFF, 0xFF]);
+}
+
+#[test]
+fn empty() {
+    let words: [Cell<u32>; 0] = [];
+    assert!(transmute_cells_to_bytes(&words).is_empty());
+    assert_eq!(transmute_byte_cells_many::<u32, SingleManyGuard>(transmute_cells_to_bytes(&words)).map(|w| w.len()).ok(), None);
+}
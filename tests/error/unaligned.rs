@@ -1,5 +1,6 @@
-use safe_transmute::{transmute_many_permissive, transmute_to_bytes};
-use safe_transmute::error::{UnalignedError, Error};
+use safe_transmute::{transmute_many_permissive, transmute_to_bytes, AlignOf, Aligned};
+use safe_transmute::error::{UnalignedError, ErrorReason, GuardError, Error};
+use safe_transmute::align::check_alignment;
 #[cfg(feature = "alloc")]
 use core::mem::align_of;
 #[cfg(feature = "alloc")]
@@ -66,6 +67,48 @@ fn unaligned_slicing_integers() {
     unaligned_slicing_integers_with_try_copy(bytes).unwrap();
 }
 
+#[test]
+fn offset_depends_on_alignment_only() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 16]);
+
+    for i in 1..4 {
+        match transmute_many_permissive::<[u32; 2]>(&bytes.data[i..]) {
+            Err(Error::Unaligned(e)) => {
+                assert_eq!(e.offset, 4 - i);
+                assert_eq!(e.alignment, 4);
+                assert_eq!(e.modulus, i);
+                assert_eq!(transmute_many_permissive::<[u32; 2]>(&bytes.data[i + e.offset..]).map(|words| words.len()), Ok(1));
+            }
+            _ => panic!("expected an alignment error"),
+        }
+    }
+}
+
+#[test]
+fn zero_sized() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00u8; 8]);
+
+    let e = check_alignment::<_, [u32; 0]>(&bytes.data[1..]).unwrap_err();
+    assert_eq!(e.offset, 3);
+    assert_eq!(e.alignment, 4);
+    assert_eq!(e.modulus, 1);
+    assert_eq!(check_alignment::<_, [u32; 0]>(&bytes.data[4..]), Ok(()));
+}
+
+#[test]
+fn without_src() {
+    let bytes = Aligned::<AlignOf<u16>, _>::new([0x00; 4]);
+    match transmute_many_permissive::<u16>(&bytes.data[1..]).map_err(Error::without_src) {
+        Err(Error::Unaligned(e)) => {
+            assert_eq!(e.offset, 1);
+            assert_eq!(e.alignment, 2);
+            assert_eq!(e.modulus, 1);
+            assert!(e.source.is_empty());
+        }
+        _ => panic!("expected an alignment error"),
+    }
+}
+
 #[cfg(feature = "alloc")]
 fn unaligned_slicing_integers_with_try_copy<'a>(bytes: &'a [u8]) -> Result<(), Error<'a, u8, u64>> {
     if align_of::<u64>() != 8 {  // i686
@@ -88,3 +131,51 @@ fn unaligned_slicing_integers_with_try_copy<'a>(bytes: &'a [u8]) -> Result<(), E
 
     Ok(())
 }
+
+#[test]
+fn unaligned_copy_into() {
+    let words: [u16; 4] = [0x01FF, 0x02EE, 0x03DD, 0x04CC];
+    let bytes = transmute_to_bytes(&words);
+
+    let err = match transmute_many_permissive::<u16>(&bytes[1..]) {
+        Ok(_) => unreachable!(),
+        Err(Error::Unaligned(e)) => e,
+        Err(e) => panic!("Expected `UnalignedError`, got {}", e),
+    };
+
+    let mut storage = [0u16; 4];
+    assert_eq!(err.copy_into(&mut storage), Ok(3));
+    assert_eq!(transmute_to_bytes(&storage[..3]), &bytes[1..7]);
+    assert_eq!(storage[3], 0);
+
+    assert_eq!(err.copy_into(&mut storage[..2]),
+               Err(GuardError {
+                   required: 6,
+                   actual: 4,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn realign() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00, 0xAA, 0xBB, 0xCC, 0x01, 0x00, 0x00, 0x00, 0xDD]);
+    for i in 1..4 {
+        match transmute_many_permissive::<u32>(&bytes.data[i..]) {
+            Err(Error::Unaligned(e)) => {
+                assert_eq!(e.realign(), (&bytes.data[i..4], &[u32::from_le(1)][..], &[0xDD][..]));
+            }
+            _ => panic!("expected an alignment error"),
+        }
+    }
+}
+
+#[test]
+fn realign_too_short() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 4]);
+    for i in 1..4 {
+        match transmute_many_permissive::<u32>(&bytes.data[i..]) {
+            Err(Error::Unaligned(e)) => assert_eq!(e.realign(), (&bytes.data[i..], &[][..], &[][..])),
+            _ => panic!("expected an alignment error"),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, transmute_many, transmute_one, TransmuteExt, ErrorReason, AsBytesExt, GuardError, Error};
+#[cfg(feature = "alloc")]
+use safe_transmute::TransmuteVecExt;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+#[test]
+fn slice() {
+    let words = [u16::from_le(1), u16::from_le(2)];
+    let bytes: &[u8] = words.as_bytes();
+    assert_eq!(bytes.transmute_one::<u16>(), Ok(u16::from_le(1)));
+    assert_eq!(bytes.transmute_many::<u16, PedanticGuard>(), Ok(&words[..]));
+    assert_eq!(bytes[..0].transmute_many::<u16, SingleManyGuard>(),
+               Err(Error::Guard(GuardError {
+                   required: 16 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn array() {
+    let mut bytes = [0x01u8, 0x02, 0x03];
+    assert_eq!(bytes.transmute_one::<u8>(), Ok(0x01));
+    assert_eq!(bytes.transmute_many::<u8, PedanticGuard>(), Ok(&[0x01, 0x02, 0x03][..]));
+    bytes.transmute_many_mut::<u8, PedanticGuard>().unwrap()[1] = 0xFF;
+    assert_eq!(bytes, [0x01, 0xFF, 0x03]);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec() {
+    let mut bytes = vec![0x01u8, 0x02];
+    assert_eq!(bytes.transmute_many::<u8, PedanticGuard>(), Ok(&[0x01, 0x02][..]));
+    bytes.transmute_many_mut::<u8, PedanticGuard>().unwrap()[0] = 0xFF;
+    assert_eq!(bytes.transmute_vec::<u8>(), Ok(vec![0xFF, 0x02]));
+    assert_eq!(Vec::new().transmute_vec::<u8>(), Ok(vec![]));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec_incompatible_target() {
+    let bytes = vec![0x01u8, 0x00, 0x02, 0x00];
+    let error = bytes.transmute_vec::<u16>().unwrap_err();
+    match error {
+        Error::IncompatibleVecTarget(_) => {}
+        ref e => panic!("Expected `IncompatibleVecTarget`, got {:?}", e),
+    }
+    assert_eq!(error.copy(), Ok(vec![u16::from_le(1), u16::from_le(2)]));
+
+    match Vec::new().transmute_vec::<u16>() {
+        Err(Error::IncompatibleVecTarget(e)) => assert_eq!(e.copy(), vec![]),
+        r => panic!("Expected `IncompatibleVecTarget`, got {:?}", r),
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn references() {
+    let vec = vec![0x01u8, 0x02, 0x03, 0x04];
+    let array = [0x01u8, 0x02, 0x03, 0x04];
+    let vec_ref: &Vec<u8> = &vec;
+    let array_ref: &[u8; 4] = &array;
+
+    assert_eq!(vec_ref.transmute_one::<[u8; 2]>(), Ok([0x01, 0x02]));
+    assert_eq!(array_ref.transmute_one::<[u8; 2]>(), Ok([0x01, 0x02]));
+    assert_eq!(vec_ref.transmute_many::<[u8; 2], PedanticGuard>(), Ok(&[[0x01, 0x02], [0x03, 0x04]][..]));
+    assert_eq!(array_ref.transmute_many::<[u8; 2], PedanticGuard>(), Ok(&[[0x01, 0x02], [0x03, 0x04]][..]));
+}
+
+#[test]
+fn guard_error() {
+    let bytes = [0x01u8, 0x02, 0x03];
+    assert_eq!(bytes.transmute_many::<[u8; 2], PedanticGuard>(), transmute_many::<[u8; 2], PedanticGuard>(&bytes));
+    assert_eq!(bytes.transmute_many::<[u8; 2], PedanticGuard>(),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+    assert_eq!(bytes[..1].transmute_one::<[u8; 2]>(), transmute_one::<[u8; 2]>(&bytes[..1]));
+}
+
+#[test]
+fn as_bytes() {
+    let mut words = [0u32; 2];
+    assert_eq!(words.as_bytes(), &[0x00; 8]);
+    words.as_bytes_mut()[4..].copy_from_slice(&0x0102_0304u32.to_ne_bytes());
+    assert_eq!(words, [0, 0x0102_0304]);
+    assert_eq!(words[..1].as_bytes().len(), 4);
+}
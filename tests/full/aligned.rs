@@ -0,0 +1,66 @@
+use safe_transmute::align::{Align16, Align64, Align2, Align4, Align8};
+use safe_transmute::{transmute_many_permissive_aligned_mut, transmute_many_permissive_aligned, transmute_many_aligned_mut, transmute_many_aligned,
+                     SingleManyGuard, PedanticGuard, ErrorReason, GuardError, AlignOf, Aligned};
+use core::mem::align_of;
+
+
+#[test]
+fn alignment() {
+    assert_eq!(align_of::<Aligned<Align2, [u8; 3]>>(), 2);
+    assert_eq!(align_of::<Aligned<Align4, [u8; 3]>>(), 4);
+    assert_eq!(align_of::<Aligned<Align8, [u8; 3]>>(), 8);
+    assert_eq!(align_of::<Aligned<Align16, [u8; 3]>>(), 16);
+    assert_eq!(align_of::<Aligned<Align64, [u8; 3]>>(), 64);
+    assert_eq!(align_of::<Aligned<AlignOf<u64>, [u8; 3]>>(), align_of::<u64>());
+
+    let buffers = [Aligned::<Align64, _>::new([0u8; 3]); 4];
+    for buffer in &buffers {
+        assert_eq!((buffer.data.as_ptr() as usize) % 64, 0);
+    }
+}
+
+#[test]
+fn too_short() {
+    let bytes = Aligned::<Align8, _>::new([0x00; 7]);
+    assert_eq!(transmute_many_aligned::<u64, SingleManyGuard, _>(&bytes),
+               Err(GuardError {
+                   required: 64 / 8,
+                   actual: 7,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(transmute_many_permissive_aligned::<u64, _>(&bytes), &[]);
+}
+
+#[test]
+fn inexact() {
+    let mut bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 5]);
+    assert_eq!(transmute_many_aligned_mut::<u32, PedanticGuard, _>(&mut bytes),
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert_eq!(transmute_many_permissive_aligned_mut::<u32, _>(&mut bytes), &mut [0]);
+}
+
+#[test]
+fn just_enough() {
+    let words: [u32; 2] = [0x0102_0304, 0x0506_0708];
+    let mut bytes = Aligned::<Align4, [u8; 8]>::default();
+    transmute_many_aligned_mut::<u32, PedanticGuard, _>(&mut bytes).unwrap().copy_from_slice(&words);
+    assert_eq!(transmute_many_aligned::<u32, PedanticGuard, _>(&bytes), Ok(&words[..]));
+    assert_eq!(transmute_many_aligned::<u16, PedanticGuard, _>(&bytes).map(|w| w.len()), Ok(4));
+    assert_eq!(transmute_many_aligned::<u8, PedanticGuard, _>(&bytes), Ok(bytes.as_ref()));
+}
+
+#[test]
+fn slices() {
+    let mut storage = Aligned::<Align16, _>::new([0xFFu8; 32]);
+    {
+        let bytes: &mut Aligned<Align16, [u8]> = &mut storage;
+        assert_eq!(bytes.as_bytes().len(), 32);
+        assert_eq!(transmute_many_permissive_aligned_mut::<u64, _>(bytes).len(), 4);
+        bytes.as_mut_bytes()[0] = 0x00;
+    }
+    assert_eq!(storage.into_inner()[..2], [0x00, 0xFF]);
+}
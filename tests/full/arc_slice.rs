@@ -0,0 +1,56 @@
+#![cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+
+
+use safe_transmute::error::IncompatibleArcTargetError;
+use safe_transmute::{transmute_to_bytes, transmute_arc_slice, Error};
+use alloc::sync::Arc;
+
+
+#[test]
+fn bad_size() {
+    let data: Arc<[u16]> = Arc::from(&[1, 2, 3][..]);
+    assert_eq!(transmute_arc_slice::<u16, [u8; 4]>(data.clone()),
+               Err(Error::IncompatibleArcTarget(IncompatibleArcTargetError::new(data))));
+}
+
+#[test]
+fn just_enough() {
+    let data: Arc<[u16]> = Arc::from(&[0x0100u16, 0x0200u16][..]);
+    let words = transmute_arc_slice::<u16, i16>(data.clone()).unwrap();
+    assert_eq!(*words, [0x0100i16, 0x0200i16]);
+    assert_eq!(words.as_ptr() as usize, data.as_ptr() as usize);
+    assert_eq!(Arc::strong_count(&data), 2);
+
+    drop(words);
+    assert_eq!(Arc::strong_count(&data), 1);
+}
+
+#[test]
+fn bad_alignment() {
+    let data: Arc<[u64]> = Arc::from(&[3, 2, 1][..]);
+    match transmute_arc_slice::<u64, [u16; 4]>(data.clone()) {
+        Ok(_) => unreachable!(),
+        Err(Error::IncompatibleArcTarget(e)) => {
+            assert!(Arc::ptr_eq(&e.arc, &data));
+            assert_eq!(transmute_to_bytes(&e.copy()), transmute_to_bytes(&data));
+        }
+        Err(e) => panic!("Expected `IncompatibleArcTargetError`, got {}", e),
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn shared_between_threads() {
+    let data: Arc<[u32]> = Arc::from(&[0x0102_0304, 0x0506_0708][..]);
+    let words = transmute_arc_slice::<u32, i32>(data.clone()).unwrap();
+
+    let handle = std::thread::spawn(move || words.iter().map(|&w| w as u32).sum::<u32>());
+    assert_eq!(handle.join().unwrap(), data.iter().sum::<u32>());
+}
+
+#[test]
+fn error_copy() {
+    let data: Arc<[u64]> = Arc::from(&[3, 2, 1][..]);
+    let err = transmute_arc_slice::<u64, [u16; 4]>(data.clone()).unwrap_err();
+    assert_eq!(transmute_to_bytes(&err.copy().unwrap()), transmute_to_bytes(&data));
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::error::IncompatibleBoxTargetError;
+use safe_transmute::{transmute_box_slice, Error};
+use alloc::boxed::Box;
+
+
+#[test]
+fn bad_size() {
+    assert_eq!(transmute_box_slice::<u16, [u16; 2]>(vec![].into_boxed_slice()),
+               Err(Error::IncompatibleBoxTarget(IncompatibleBoxTargetError::new(vec![].into_boxed_slice()))));
+    assert_eq!(transmute_box_slice::<u16, [u8; 4]>(vec![1, 2, 3].into_boxed_slice()),
+               Err(Error::IncompatibleBoxTarget(IncompatibleBoxTargetError::new(vec![1, 2, 3].into_boxed_slice()))));
+}
+
+#[test]
+fn just_enough() {
+    assert_eq!(transmute_box_slice::<u8, i8>(vec![0x00, 0x01].into_boxed_slice()),
+               Ok(vec![0x00i8, 0x01i8].into_boxed_slice()));
+    assert_eq!(transmute_box_slice::<u16, i16>(vec![0x0100u16, 0x0200u16].into_boxed_slice()),
+               Ok(vec![0x0100i16, 0x0200i16].into_boxed_slice()));
+}
+
+#[test]
+fn bad_alignment() {
+    assert_eq!(transmute_box_slice::<u16, [u8; 2]>(vec![8, 8, 8].into_boxed_slice()),
+               Err(Error::IncompatibleBoxTarget(IncompatibleBoxTargetError::new(vec![8, 8, 8].into_boxed_slice()))));
+    assert_eq!(transmute_box_slice::<u64, [u16; 4]>(vec![3, 2, 1].into_boxed_slice()),
+               Err(Error::IncompatibleBoxTarget(IncompatibleBoxTargetError::new(vec![3, 2, 1].into_boxed_slice()))));
+}
+
+#[test]
+fn copy_fallback() {
+    let words = [0x0102u16, 0x0304u16];
+    let copied: Box<[[u8; 2]]> = match transmute_box_slice::<u16, [u8; 2]>(words.to_vec().into_boxed_slice()) {
+        Ok(_) => unreachable!(),
+        Err(Error::IncompatibleBoxTarget(e)) => e.copy(),
+        Err(e) => panic!("Expected `IncompatibleBoxTargetError`, got {}", e),
+    };
+    assert_eq!(*copied, [0x0102u16.to_ne_bytes(), 0x0304u16.to_ne_bytes()]);
+}
@@ -0,0 +1,54 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::error::IncompatibleBoxTargetError;
+use safe_transmute::{ErrorReason, GuardError, Error, transmute_box};
+use alloc::boxed::Box;
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_box::<[u8; 4]>(vec![].into_boxed_slice()),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 0,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+    assert_eq!(transmute_box::<[u8; 4]>(vec![0x00, 0x01, 0x02].into_boxed_slice()),
+               Err(Error::Guard(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn just_enough() {
+    assert_eq!(transmute_box::<[u8; 4]>(vec![0x00, 0x01, 0x02, 0x03].into_boxed_slice()),
+               Ok(Box::new([0x00, 0x01, 0x02, 0x03])));
+    assert_eq!(transmute_box::<i8>(vec![0xFF].into_boxed_slice()), Ok(Box::new(-1)));
+}
+
+#[test]
+fn too_much() {
+    assert_eq!(transmute_box::<[u8; 2]>(vec![0x00, 0x01, 0x02].into_boxed_slice()),
+               Err(Error::Guard(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn bad_alignment() {
+    assert_eq!(transmute_box::<u16>(vec![0x00, 0x01].into_boxed_slice()),
+               Err(Error::IncompatibleBoxTarget(IncompatibleBoxTargetError::new(vec![0x00, 0x01].into_boxed_slice()))));
+
+    let bytes = 0x0102_0304u32.to_ne_bytes();
+    let copied: Box<[u32]> = match transmute_box::<u32>(bytes.to_vec().into_boxed_slice()) {
+        Ok(_) => unreachable!(),
+        Err(Error::IncompatibleBoxTarget(e)) => e.copy(),
+        Err(e) => panic!("Expected `IncompatibleBoxTargetError`, got {}", e),
+    };
+    assert_eq!(*copied, [0x0102_0304]);
+}
@@ -0,0 +1,60 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, transmute_to_bytes, copy_from_bytes};
+
+
+#[test]
+fn too_short() {
+    let mut words = [0u32; 2];
+    assert_eq!(copy_from_bytes::<u32, PedanticGuard>(&mut words, &[0x00, 0x01, 0x02]),
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(words, [0, 0]);
+}
+
+#[test]
+fn inexact() {
+    let mut words = [0u32; 2];
+    assert_eq!(copy_from_bytes::<u32, PedanticGuard>(&mut words, &[0x00, 0x01, 0x02, 0x03, 0x04]),
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert_eq!(words, [0, 0]);
+}
+
+#[test]
+fn refill() {
+    let words: &[u32] = &[0x0102_0304, 0x0506_0708, 0x090A_0B0C];
+    let bytes = transmute_to_bytes(words);
+
+    let mut buffer = [0u32; 2];
+    assert_eq!(copy_from_bytes::<u32, SingleManyGuard>(&mut buffer, &bytes[..8]), Ok(2));
+    assert_eq!(buffer, words[..2]);
+
+    assert_eq!(copy_from_bytes::<u32, SingleManyGuard>(&mut buffer, &bytes[8..]), Ok(1));
+    assert_eq!(buffer, [words[2], words[1]]);
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u32] = &[0x0102_0304, 0x0506_0708, 0x090A_0B0C];
+    let bytes = transmute_to_bytes(words);
+
+    let mut buffer = [0u32; 2];
+    assert_eq!(copy_from_bytes::<u32, SingleManyGuard>(&mut buffer, &bytes[1..11]), Ok(2));
+    assert_eq!(transmute_to_bytes(&buffer), &bytes[1..9]);
+}
+
+#[test]
+fn destination_too_short() {
+    let mut buffer = [0u32; 1];
+    assert_eq!(copy_from_bytes::<u32, PedanticGuard>(&mut buffer, &[0; 8]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 4,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_bytes, transmute_cow};
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_cow::<u16, SingleManyGuard>(Cow::Borrowed(&[0x00])),
+               Err(Error::Guard(GuardError {
+                   required: 16 / 8,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(transmute_cow::<u16, PedanticGuard>(Cow::Owned(vec![0x00, 0x01, 0x02])),
+               Err(Error::Guard(GuardError {
+                   required: 16 / 8,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn borrowed() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+
+    match transmute_cow::<u16, PedanticGuard>(Cow::Borrowed(bytes)).unwrap() {
+        Cow::Borrowed(borrowed) => assert_eq!(borrowed, words),
+        Cow::Owned(owned) => panic!("Expected borrowed data, got {:?}", owned),
+    }
+    match transmute_cow::<u16, PedanticGuard>(Cow::Borrowed(&bytes[1..5])).unwrap() {
+        Cow::Borrowed(borrowed) => panic!("Expected owned data, got {:?}", borrowed),
+        Cow::Owned(owned) => assert_eq!(owned, [u16::from_ne_bytes([bytes[1], bytes[2]]), u16::from_ne_bytes([bytes[3], bytes[4]])]),
+    }
+}
+
+#[test]
+fn owned_reused() {
+    let bytes: Vec<u8> = vec![0x00, 0x01, 0xFF];
+    let ptr = bytes.as_ptr() as usize;
+
+    match transmute_cow::<i8, PedanticGuard>(Cow::Owned(bytes)).unwrap() {
+        Cow::Borrowed(borrowed) => panic!("Expected owned data, got {:?}", borrowed),
+        Cow::Owned(owned) => {
+            assert_eq!(owned, [0x00, 0x01, -0x01]);
+            assert_eq!(owned.as_ptr() as usize, ptr);
+        }
+    }
+}
+
+#[test]
+fn owned_copied() {
+    let words: &[u16] = &[0x0100, 0x0200];
+    let bytes = transmute_to_bytes(words).to_vec();
+
+    assert_eq!(*transmute_cow::<u16, SingleManyGuard>(Cow::Owned(bytes)).unwrap(), *words);
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{extend_from_bytes_with_tail, SingleManyGuard, PermissiveGuard, PedanticGuard, ErrorReason, GuardError, Error, transmute_to_bytes,
+                     extend_from_bytes};
+use alloc::vec::Vec;
+
+
+#[test]
+fn guard_failure() {
+    let mut words = vec![0x0102_0304u32];
+    assert_eq!(extend_from_bytes::<u32, PedanticGuard>(&mut words, &[0x00, 0x01, 0x02, 0x03, 0x04]),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+    assert_eq!(extend_from_bytes_with_tail::<u32, SingleManyGuard>(&mut words, &[0x00]),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(words, [0x0102_0304]);
+}
+
+#[test]
+fn empty() {
+    let mut words = Vec::<u32>::new();
+    assert_eq!(extend_from_bytes::<u32, PermissiveGuard>(&mut words, &[]), Ok(()));
+    assert_eq!(extend_from_bytes_with_tail::<u32, PermissiveGuard>(&mut words, &[0x00, 0x01]), Ok(&[0x00, 0x01][..]));
+    assert!(words.is_empty());
+}
+
+#[test]
+fn unaligned() {
+    let source: &[u32] = &[0x0102_0304, 0x0506_0708, 0x090A_0B0C, 0x0D0E_0F10];
+    let bytes = transmute_to_bytes(source);
+
+    let mut words = vec![0xFFFF_FFFFu32];
+    assert_eq!(extend_from_bytes::<u32, PedanticGuard>(&mut words, &bytes[1..13]), Ok(()));
+    assert_eq!(words.len(), 4);
+    assert_eq!(words[0], 0xFFFF_FFFF);
+    assert_eq!(transmute_to_bytes(&words[1..]), &bytes[1..13]);
+}
+
+#[test]
+fn chunked() {
+    let source: &[u32] = &[0x0102_0304, 0x0506_0708, 0x090A_0B0C, 0x0D0E_0F10];
+    let bytes = transmute_to_bytes(source);
+
+    let mut words = Vec::new();
+    let mut carry = Vec::new();
+    for chunk in bytes.chunks(3) {
+        carry.extend_from_slice(chunk);
+        let consumed = {
+            let tail = extend_from_bytes_with_tail::<u32, PermissiveGuard>(&mut words, &carry).unwrap();
+            carry.len() - tail.len()
+        };
+        carry.drain(..consumed);
+    }
+
+    assert_eq!(words, source);
+    assert!(carry.is_empty());
+}
@@ -0,0 +1,69 @@
+use safe_transmute::{SingleManyGuard, PedanticGuard, ErrorReason, GuardError, transmute_to_bytes, transmute_many_into};
+
+
+#[test]
+fn too_short() {
+    let mut words = [0u16; 2];
+    assert_eq!(transmute_many_into::<u16, SingleManyGuard>(&[0x00], &mut words),
+               Err(GuardError {
+                   required: 16 / 8,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(words, [0, 0]);
+}
+
+#[test]
+fn just_enough() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+
+    let mut storage = [0u16; 3];
+    assert_eq!(transmute_many_into::<u16, PedanticGuard>(bytes, &mut storage), Ok(3));
+    assert_eq!(storage, *words);
+
+    let mut storage = [0u16; 4];
+    assert_eq!(transmute_many_into::<u16, PedanticGuard>(&bytes[..4], &mut storage), Ok(2));
+    assert_eq!(storage, [0x0100, 0x0200, 0, 0]);
+}
+
+#[test]
+fn unaligned() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+
+    let mut storage = [0u16; 3];
+    assert_eq!(transmute_many_into::<u16, SingleManyGuard>(&bytes[1..], &mut storage), Ok(2));
+    assert_eq!(transmute_to_bytes(&storage[..2]), &bytes[1..5]);
+}
+
+#[test]
+fn too_much() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+
+    let mut storage = [0u16; 2];
+    assert_eq!(transmute_many_into::<u16, SingleManyGuard>(bytes, &mut storage),
+               Err(GuardError {
+                   required: 6,
+                   actual: 4,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn source_checked_before_destination() {
+    let mut storage: [u16; 0] = [];
+    assert_eq!(transmute_many_into::<u16, SingleManyGuard>(&[0x00], &mut storage),
+               Err(GuardError {
+                   required: 16 / 8,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(transmute_many_into::<u16, SingleManyGuard>(&[0x00; 2], &mut storage),
+               Err(GuardError {
+                   required: 2,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::{SingleManyGuard, ErrorReason, GuardError, transmute_many_permissive_or_copy, transmute_many_or_copy_unchecked,
+                     transmute_many_pedantic_or_copy, transmute_many_or_copy, transmute_to_bytes};
+use alloc::borrow::Cow;
+
+
+#[test]
+fn too_short() {
+    assert_eq!(transmute_many_or_copy::<u16, SingleManyGuard>(&[]),
+               Err(GuardError {
+                   required: 16 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(transmute_many_pedantic_or_copy::<u16>(&transmute_to_bytes::<u16>(&[0])[..1]),
+               Err(GuardError {
+                   required: 16 / 8,
+                   actual: 1,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn aligned_borrows() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+
+    match transmute_many_or_copy::<u16, SingleManyGuard>(&bytes[..5]).unwrap() {
+        Cow::Borrowed(borrowed) => assert_eq!(borrowed, &words[..2]),
+        Cow::Owned(owned) => panic!("Expected borrowed data, got {:?}", owned),
+    }
+    assert_eq!(transmute_many_pedantic_or_copy::<u16>(bytes), Ok(Cow::Borrowed(words)));
+    assert_eq!(transmute_many_permissive_or_copy::<u16>(&bytes[2..]), Cow::Borrowed(&words[1..]));
+}
+
+#[test]
+fn unaligned_copies() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+    let expected = [u16::from_ne_bytes([bytes[1], bytes[2]]), u16::from_ne_bytes([bytes[3], bytes[4]])];
+
+    match transmute_many_or_copy::<u16, SingleManyGuard>(&bytes[1..]).unwrap() {
+        Cow::Borrowed(borrowed) => panic!("Expected owned data, got {:?}", borrowed),
+        Cow::Owned(owned) => assert_eq!(owned, expected),
+    }
+    assert_eq!(*transmute_many_pedantic_or_copy::<u16>(&bytes[1..5]).unwrap(), expected);
+    assert_eq!(*transmute_many_permissive_or_copy::<u16>(&bytes[1..]), expected);
+    assert_eq!(*transmute_many_permissive_or_copy::<u16>(&bytes[1..2]), []);
+}
+
+#[test]
+fn unchecked() {
+    #[derive(Clone, Debug, PartialEq)]
+    #[repr(C)]
+    struct Pair(u8, u8);
+
+    let bytes: &[u8] = &[0x00, 0x01, 0x02, 0x03, 0x04];
+    unsafe {
+        assert_eq!(*transmute_many_or_copy_unchecked::<Pair, SingleManyGuard>(&bytes[1..]).unwrap(),
+                   [Pair(0x01, 0x02), Pair(0x03, 0x04)]);
+    }
+}
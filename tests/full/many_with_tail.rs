@@ -0,0 +1,57 @@
+use safe_transmute::{transmute_many_mut_with_tail, transmute_many_with_tail, PermissiveGuard, SingleManyGuard, PedanticGuard, ErrorReason, GuardError, AlignOf,
+                     Aligned, Error};
+
+
+#[test]
+fn too_short() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 3]);
+    assert_eq!(transmute_many_with_tail::<u32, SingleManyGuard>(&bytes.data),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+    assert_eq!(transmute_many_with_tail::<u32, PermissiveGuard>(&bytes.data), Ok((&[][..], &bytes.data[..])));
+}
+
+#[test]
+fn just_enough() {
+    let bytes = Aligned::<AlignOf<u16>, _>::new([0x01, 0x00, 0x02, 0x00]);
+    assert_eq!(transmute_many_with_tail::<u16, PedanticGuard>(&bytes.data),
+               Ok((&[u16::from_le(0x0001), u16::from_le(0x0002)][..], &[][..])));
+}
+
+#[test]
+fn too_much() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x01, 0x00, 0x00, 0x00, 0xAA, 0xBB, 0xCC]);
+    assert_eq!(transmute_many_with_tail::<u32, SingleManyGuard>(&bytes.data),
+               Ok((&[u32::from_le(0x0000_0001)][..], &[0xAA, 0xBB, 0xCC][..])));
+    assert_eq!(transmute_many_with_tail::<u32, PedanticGuard>(&bytes.data),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 7,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 9]);
+    match transmute_many_with_tail::<u32, PermissiveGuard>(&bytes.data[1..]) {
+        Err(Error::Unaligned(_)) => {}
+        _ => panic!("expected an alignment error"),
+    }
+}
+
+#[test]
+fn mutable() {
+    let mut bytes = Aligned::<AlignOf<u16>, _>::new([0x00; 5]);
+    {
+        let (words, tail) = transmute_many_mut_with_tail::<u16, PermissiveGuard>(&mut bytes.data).unwrap();
+        assert_eq!(words.len(), 2);
+        assert_eq!(tail.len(), 1);
+        words[1] = u16::from_le(0x0201);
+        tail[0] = 0x03;
+    }
+    assert_eq!(bytes.data, [0x00, 0x00, 0x01, 0x02, 0x03]);
+}
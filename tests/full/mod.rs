@@ -1,6 +1,19 @@
+mod extend_from_bytes;
+mod copy_from_bytes;
 mod many_permissive;
+mod many_with_tail;
+mod skip_unaligned;
 mod many_pedantic;
 mod one_pedantic;
+mod many_or_copy;
+mod arc_slice;
+mod box_slice;
+mod many_into;
+mod rc_slice;
+mod aligned;
+mod vec_in;
+mod boxed;
 mod many;
+mod cow;
 mod one;
 mod vec;
@@ -0,0 +1,46 @@
+#![cfg(feature = "alloc")]
+
+
+use safe_transmute::error::IncompatibleRcTargetError;
+use safe_transmute::{transmute_to_bytes, transmute_rc_slice, Error};
+use alloc::rc::Rc;
+
+
+#[test]
+fn bad_size() {
+    let data: Rc<[u16]> = Rc::from(&[1, 2, 3][..]);
+    assert_eq!(transmute_rc_slice::<u16, [u8; 4]>(data.clone()),
+               Err(Error::IncompatibleRcTarget(IncompatibleRcTargetError::new(data))));
+}
+
+#[test]
+fn just_enough() {
+    let data: Rc<[u16]> = Rc::from(&[0x0100u16, 0x0200u16][..]);
+    let words = transmute_rc_slice::<u16, i16>(data.clone()).unwrap();
+    assert_eq!(*words, [0x0100i16, 0x0200i16]);
+    assert_eq!(words.as_ptr() as usize, data.as_ptr() as usize);
+    assert_eq!(Rc::strong_count(&data), 2);
+
+    drop(words);
+    assert_eq!(Rc::strong_count(&data), 1);
+}
+
+#[test]
+fn bad_alignment() {
+    let data: Rc<[u64]> = Rc::from(&[3, 2, 1][..]);
+    match transmute_rc_slice::<u64, [u16; 4]>(data.clone()) {
+        Ok(_) => unreachable!(),
+        Err(Error::IncompatibleRcTarget(e)) => {
+            assert!(Rc::ptr_eq(&e.rc, &data));
+            assert_eq!(transmute_to_bytes(&e.copy()), transmute_to_bytes(&data));
+        }
+        Err(e) => panic!("Expected `IncompatibleRcTargetError`, got {}", e),
+    }
+}
+
+#[test]
+fn error_copy() {
+    let data: Rc<[u64]> = Rc::from(&[3, 2, 1][..]);
+    let err = transmute_rc_slice::<u64, [u16; 4]>(data.clone()).unwrap_err();
+    assert_eq!(transmute_to_bytes(&err.copy().unwrap()), transmute_to_bytes(&data));
+}
@@ -0,0 +1,56 @@
+use safe_transmute::{transmute_many_skip_unaligned_mut, transmute_many_skip_unaligned, PermissiveGuard, SingleManyGuard, PedanticGuard, ErrorReason,
+                     GuardError, AlignOf, Aligned};
+
+
+#[test]
+fn aligned() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00]);
+    assert_eq!(transmute_many_skip_unaligned::<u32, PedanticGuard>(&bytes.data),
+               Ok((&[][..], &[u32::from_le(1), u32::from_le(2)][..], &[][..])));
+}
+
+#[test]
+fn unaligned() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00, 0xAA, 0xBB, 0xCC, 0x01, 0x00, 0x00, 0x00, 0xDD]);
+    for i in 1..4 {
+        let (prefix, words, suffix) = transmute_many_skip_unaligned::<u32, PermissiveGuard>(&bytes.data[i..]).unwrap();
+        assert_eq!(prefix, &bytes.data[i..4]);
+        assert_eq!(words, [u32::from_le(1)]);
+        assert_eq!(suffix, &[0xDD]);
+    }
+}
+
+#[test]
+fn guard_on_values() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 9]);
+    assert_eq!(transmute_many_skip_unaligned::<u32, PedanticGuard>(&bytes.data[1..8]), Ok((&bytes.data[1..4], &[0][..], &[][..])));
+    assert_eq!(transmute_many_skip_unaligned::<u32, PedanticGuard>(&bytes.data[1..]), Ok((&bytes.data[1..4], &[0][..], &bytes.data[8..])));
+    assert_eq!(transmute_many_skip_unaligned::<u32, SingleManyGuard>(&bytes.data[1..6]),
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn too_short_to_align() {
+    let bytes = Aligned::<AlignOf<u64>, _>::new([0x00; 8]);
+    for i in 1..8 {
+        assert_eq!(transmute_many_skip_unaligned::<u64, PermissiveGuard>(&bytes.data[i..]), Ok((&bytes.data[i..], &[][..], &[][..])));
+    }
+    assert_eq!(transmute_many_skip_unaligned::<u64, PermissiveGuard>(&bytes.data[1..2]), Ok((&bytes.data[1..2], &[][..], &[][..])));
+}
+
+#[test]
+fn mutable() {
+    let mut bytes = Aligned::<AlignOf<u16>, _>::new([0x00; 6]);
+    {
+        let (prefix, words, suffix) = transmute_many_skip_unaligned_mut::<u16, PermissiveGuard>(&mut bytes.data[1..]).unwrap();
+        assert_eq!(words.len(), 2);
+        prefix[0] = 0xAA;
+        words[1] = u16::from_le(0x0201);
+        assert!(suffix.is_empty());
+    }
+    assert_eq!(bytes.data, [0x00, 0xAA, 0x00, 0x00, 0x01, 0x02]);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "allocator_api")]
+
+
+use safe_transmute::{transmute_to_bytes_vec_in, transmute_vec_in, transmute_vec, Error};
+use alloc::alloc::Global;
+use self::super::super::CountingAlloc;
+use alloc::vec::Vec;
+use core::cell::Cell;
+
+
+#[test]
+fn just_enough() {
+    let live = Cell::new(0);
+    {
+        let mut bytes = Vec::new_in(CountingAlloc(&live));
+        bytes.extend_from_slice(&[0x00u8, 0x01, 0xFF]);
+        assert_eq!(live.get(), 1);
+
+        let signed = transmute_vec_in::<u8, i8, _>(bytes).unwrap();
+        assert_eq!(signed, [0x00, 0x01, -0x01]);
+        assert_eq!(live.get(), 1);
+    }
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn bad_size() {
+    let live = Cell::new(0);
+    {
+        let mut words = Vec::new_in(CountingAlloc(&live));
+        words.extend_from_slice(&[0x0102u16, 0x0304]);
+
+        let err = transmute_vec_in::<u16, [u16; 2], _>(words).unwrap_err();
+        assert_eq!(err.vec, [0x0102, 0x0304]);
+        assert_eq!(live.get(), 1);
+
+        let copied = err.copy();
+        assert_eq!(copied, [[0x0102, 0x0304]]);
+        assert_eq!(live.get(), 2);
+    }
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn to_bytes() {
+    let live = Cell::new(0);
+    {
+        let mut words = Vec::new_in(CountingAlloc(&live));
+        words.push(0x0102u16);
+
+        let bytes = transmute_to_bytes_vec_in(words).unwrap_or_else(|e| e.copy());
+        assert_eq!(bytes, 0x0102u16.to_ne_bytes());
+        assert_eq!(live.get(), 1);
+    }
+    assert_eq!(live.get(), 0);
+}
+
+#[test]
+fn global_into_error() {
+    fn via_vec_in(words: Vec<u16>) -> Result<Vec<[u8; 2]>, Error<'static, u16, [u8; 2]>> {
+        Ok(transmute_vec_in::<_, _, Global>(words)?)
+    }
+    fn via_vec(words: Vec<u16>) -> Result<Vec<[u8; 2]>, Error<'static, u16, [u8; 2]>> {
+        transmute_vec(words)
+    }
+
+    assert_eq!(via_vec_in(vec![0x0102, 0x0304]), via_vec(vec![0x0102, 0x0304]));
+}
@@ -0,0 +1,78 @@
+use safe_transmute::guard::{AllOrNothingGuard, SingleValueGuard, PermissiveGuard, NotEmptyGuard, PedanticGuard, Guard, And, Or};
+use safe_transmute::error::{ErrorReason, GuardError};
+use safe_transmute::transmute_many;
+
+
+#[test]
+fn not_empty_guard() {
+    assert_eq!(NotEmptyGuard::check::<u32>(&[0]), Ok(()));
+    assert_eq!(NotEmptyGuard::check::<()>(&[0, 1]), Ok(()));
+    assert_eq!(NotEmptyGuard::check::<u32>(&[]),
+               Err(GuardError {
+                   required: 1,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn and_first_failure() {
+    type Guard1 = And<NotEmptyGuard, SingleValueGuard>;
+    assert_eq!(Guard1::check::<u16>(&[0; 2]), Ok(()));
+    assert_eq!(Guard1::check::<u16>(&[]),
+               Err(GuardError {
+                   required: 1,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(Guard1::check::<u16>(&[0; 3]),
+               Err(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+
+    type Guard2 = And<SingleValueGuard, NotEmptyGuard>;
+    assert_eq!(Guard2::check::<u16>(&[]),
+               Err(GuardError {
+                   required: 2,
+                   actual: 0,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn or_first_failure() {
+    type Guard1 = Or<SingleValueGuard, NotEmptyGuard>;
+    assert_eq!(Guard1::check::<u16>(&[0; 2]), Ok(()));
+    assert_eq!(Guard1::check::<u16>(&[0; 3]), Ok(()));
+    assert_eq!(Guard1::check::<u16>(&[]),
+               Err(GuardError {
+                   required: 2,
+                   actual: 0,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+
+    type Guard2 = Or<PedanticGuard, PermissiveGuard>;
+    assert_eq!(Guard2::check::<u64>(&[0; 3]), Ok(()));
+}
+
+#[test]
+fn nested() {
+    type Guard1 = And<Or<SingleValueGuard, AllOrNothingGuard>, NotEmptyGuard>;
+    assert_eq!(Guard1::check::<u32>(&[0; 4]), Ok(()));
+    assert_eq!(Guard1::check::<u32>(&[0; 12]), Ok(()));
+    assert_eq!(Guard1::check::<u32>(&[]),
+               Err(GuardError {
+                   required: 1,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert!(Guard1::check::<u32>(&[0; 5]).is_err());
+}
+
+#[test]
+fn in_transmutation() {
+    assert_eq!(transmute_many::<u8, And<NotEmptyGuard, PedanticGuard>>(&[1, 2]).unwrap(), [1, 2]);
+    assert!(transmute_many::<u8, And<NotEmptyGuard, PedanticGuard>>(&[]).is_err());
+}
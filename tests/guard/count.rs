@@ -0,0 +1,134 @@
+#![cfg(feature = "const_generics")]
+
+
+use safe_transmute::guard::{MultipleOfGuard, RangeCountGuard, ExactCountGuard, MaxBytesGuard, MaxCountGuard, MinCountGuard, PedanticGuard, Guard,
+                            And};
+use safe_transmute::error::{ErrorReason, GuardError};
+use safe_transmute::transmute_many;
+
+
+#[test]
+fn exact_count_guard() {
+    assert_eq!(ExactCountGuard::<3>::check::<u16>(&[0; 6]), Ok(()));
+    assert_eq!(ExactCountGuard::<3>::check::<u16>(&[0; 5]),
+               Err(GuardError {
+                   required: 6,
+                   actual: 5,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(ExactCountGuard::<3>::check::<u16>(&[0; 7]),
+               Err(GuardError {
+                   required: 6,
+                   actual: 7,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+    assert_eq!(ExactCountGuard::<0>::check::<u16>(&[]), Ok(()));
+    assert_eq!(ExactCountGuard::<16>::check::<()>(&[]), Ok(()));
+}
+
+#[test]
+fn min_count_guard() {
+    assert_eq!(MinCountGuard::<2>::check::<u32>(&[0; 8]), Ok(()));
+    assert_eq!(MinCountGuard::<2>::check::<u32>(&[0; 11]), Ok(()));
+    assert_eq!(MinCountGuard::<2>::check::<u32>(&[0; 7]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 7,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(MinCountGuard::<{ usize::MAX }>::check::<u32>(&[0; 8]),
+               Err(GuardError {
+                   required: usize::MAX,
+                   actual: 8,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn max_count_guard() {
+    assert_eq!(MaxCountGuard::<2>::check::<u32>(&[]), Ok(()));
+    assert_eq!(MaxCountGuard::<2>::check::<u32>(&[0; 5]), Ok(()));
+    assert_eq!(MaxCountGuard::<2>::check::<u32>(&[0; 8]), Ok(()));
+    assert_eq!(MaxCountGuard::<2>::check::<u32>(&[0; 9]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 9,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+    assert_eq!(MaxCountGuard::<3>::check::<u16>(&[0; 3 * 2 + 1]),
+               Err(GuardError {
+                   required: 3 * 2,
+                   actual: 3 * 2 + 1,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+    assert_eq!(MaxCountGuard::<0>::check::<u8>(&[0]),
+               Err(GuardError {
+                   required: 0,
+                   actual: 1,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+}
+
+#[test]
+fn range_count_guard() {
+    assert_eq!(RangeCountGuard::<2, 4>::check::<u16>(&[0; 4]), Ok(()));
+    assert_eq!(RangeCountGuard::<2, 4>::check::<u16>(&[0; 7]), Ok(()));
+    assert_eq!(RangeCountGuard::<2, 4>::check::<u16>(&[0; 8]), Ok(()));
+    assert_eq!(RangeCountGuard::<2, 4>::check::<u16>(&[0; 3]),
+               Err(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(RangeCountGuard::<2, 4>::check::<u16>(&[0; 9]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 9,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+}
+
+#[test]
+fn with_transmute() {
+    assert_eq!(transmute_many::<u8, ExactCountGuard<4>>(&[1, 2, 3, 4]), Ok(&[1u8, 2, 3, 4][..]));
+    assert!(transmute_many::<u8, RangeCountGuard<1, 3>>(&[1, 2, 3, 4]).is_err());
+}
+
+#[test]
+fn max_bytes_guard() {
+    assert_eq!(MaxBytesGuard::<4>::check::<u32>(&[]), Ok(()));
+    assert_eq!(MaxBytesGuard::<4>::check::<u64>(&[0; 4]), Ok(()));
+    assert_eq!(MaxBytesGuard::<4>::check::<u8>(&[0; 5]),
+               Err(GuardError {
+                   required: 4,
+                   actual: 5,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+    assert_eq!(And::<PedanticGuard, MaxBytesGuard<8>>::check::<u16>(&[0; 10]),
+               Err(GuardError {
+                   required: 8,
+                   actual: 10,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+}
+
+#[test]
+fn multiple_of_guard() {
+    assert_eq!(MultipleOfGuard::<3>::check::<u8>(&[]), Ok(()));
+    assert_eq!(MultipleOfGuard::<3>::check::<u8>(&[0; 9]), Ok(()));
+    assert_eq!(MultipleOfGuard::<2>::check::<u16>(&[0; 5]), Ok(()));
+    assert_eq!(MultipleOfGuard::<3>::check::<u16>(&[0; 4]),
+               Err(GuardError {
+                   required: 6,
+                   actual: 4,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert_eq!(MultipleOfGuard::<0>::check::<u16>(&[0; 1]), Ok(()));
+    assert_eq!(MultipleOfGuard::<0>::check::<u16>(&[0; 2]),
+               Err(GuardError {
+                   required: 0,
+                   actual: 2,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert_eq!(MultipleOfGuard::<3>::check::<()>(&[0; 4]), Ok(()));
+}
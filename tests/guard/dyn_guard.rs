@@ -0,0 +1,78 @@
+use safe_transmute::{transmute_many_into_with_guard, transmute_to_bytes, PermissiveGuard, PedanticGuard, ErrorReason, GuardError, CountRange,
+                     ExactCount, DynGuard, MaxBytes, Guard};
+use core::marker::PhantomData;
+
+
+#[test]
+fn static_guards() {
+    assert_eq!(PedanticGuard.check_bytes::<u16>(&[0; 3]), PedanticGuard::check::<u16>(&[0; 3]));
+    assert_eq!(PhantomData::<PedanticGuard>.check_bytes::<u16>(&[0; 3]), PedanticGuard::check::<u16>(&[0; 3]));
+    assert_eq!(PhantomData::<PermissiveGuard>.check_bytes::<u16>(&[]), Ok(()));
+}
+
+#[test]
+fn exact_count() {
+    assert_eq!(ExactCount(3).check_bytes::<u16>(&[0; 6]), Ok(()));
+    assert_eq!(ExactCount(3).check_bytes::<u16>(&[0; 5]),
+               Err(GuardError {
+                   required: 6,
+                   actual: 5,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(ExactCount(3).check_bytes::<u16>(&[0; 7]),
+               Err(GuardError {
+                   required: 6,
+                   actual: 7,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+    assert_eq!(ExactCount(0).check_bytes::<u16>(&[]), Ok(()));
+}
+
+#[test]
+fn max_bytes() {
+    assert_eq!(MaxBytes(4).check_bytes::<u32>(&[]), Ok(()));
+    assert_eq!(MaxBytes(4).check_bytes::<u32>(&[0; 4]), Ok(()));
+    assert_eq!(MaxBytes(4).check_bytes::<u64>(&[0; 5]),
+               Err(GuardError {
+                   required: 4,
+                   actual: 5,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+}
+
+#[test]
+fn count_range() {
+    assert_eq!(CountRange(1..=3).check_bytes::<u32>(&[0; 4]), Ok(()));
+    assert_eq!(CountRange(1..=3).check_bytes::<u32>(&[0; 12]), Ok(()));
+    assert_eq!(CountRange(1..=3).check_bytes::<u32>(&[0; 3]),
+               Err(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(CountRange(1..=3).check_bytes::<u32>(&[0; 17]),
+               Err(GuardError {
+                   required: 12,
+                   actual: 17,
+                   reason: ErrorReason::TooManyBytes,
+               }));
+    assert_eq!(CountRange(0..=usize::MAX).check_bytes::<u32>(&[0; 17]), Ok(()));
+}
+
+#[test]
+fn runtime_count() {
+    let words: &[u16] = &[0x0100, 0x0200, 0x0300];
+    let bytes = transmute_to_bytes(words);
+
+    for count in 0..5 {
+        let mut storage = [0u16; 4];
+        let result = transmute_many_into_with_guard::<u16, _>(bytes, &mut storage, &ExactCount(count));
+        if count == words.len() {
+            assert_eq!(result, Ok(3));
+            assert_eq!(storage[..3], *words);
+        } else {
+            assert!(result.is_err());
+            assert_eq!(storage, [0; 4]);
+        }
+    }
+}
@@ -1 +1,5 @@
+mod combinator;
 mod zero_sized;
+mod dyn_guard;
+mod outcome;
+mod count;
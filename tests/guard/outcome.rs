@@ -0,0 +1,57 @@
+use safe_transmute::guard::{AllOrNothingGuard, PermissiveGuard, SingleManyGuard, GuardOutcome, ExactCount, DynGuard, Guard};
+use safe_transmute::error::{ErrorReason, GuardError};
+
+
+#[test]
+fn of() {
+    assert_eq!(GuardOutcome::of::<u32>(&[]),
+               GuardOutcome {
+                   elements: 0,
+                   leftover_bytes: 0,
+               });
+    assert_eq!(GuardOutcome::of::<u32>(&[0; 11]),
+               GuardOutcome {
+                   elements: 2,
+                   leftover_bytes: 3,
+               });
+    assert_eq!(GuardOutcome::of::<()>(&[0; 3]),
+               GuardOutcome {
+                   elements: 0,
+                   leftover_bytes: 3,
+               });
+}
+
+#[test]
+fn check_outcome() {
+    assert_eq!(PermissiveGuard::check_outcome::<u16>(&[0; 3]),
+               Ok(GuardOutcome {
+                   elements: 1,
+                   leftover_bytes: 1,
+               }));
+    assert_eq!(SingleManyGuard::check_outcome::<u16>(&[0; 4]),
+               Ok(GuardOutcome {
+                   elements: 2,
+                   leftover_bytes: 0,
+               }));
+    assert_eq!(AllOrNothingGuard::check_outcome::<u16>(&[0; 3]),
+               Err(GuardError {
+                   required: 2,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
+
+#[test]
+fn check_bytes_outcome() {
+    assert_eq!(ExactCount(2).check_bytes_outcome::<u16>(&[0; 4]),
+               Ok(GuardOutcome {
+                   elements: 2,
+                   leftover_bytes: 0,
+               }));
+    assert_eq!(ExactCount(2).check_bytes_outcome::<u16>(&[0; 3]),
+               Err(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
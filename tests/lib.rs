@@ -1,4 +1,5 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
 
 
 #[cfg(feature = "std")]
@@ -12,13 +13,24 @@ extern crate alloc;
 extern crate safe_transmute;
 
 
+mod aligned_bytes;
+mod zero_sized;
+mod to_bytes;
+mod builder;
+mod atomic;
+mod uninit;
+mod zeroed;
+mod policy;
 mod guard;
 mod error;
 mod base;
+mod cell;
 mod bool;
 mod full;
 mod util;
+mod ext;
 
 
 include!("test_util/le_to_native.rs");
 include!("test_util/aligned_vec.rs");
+include!("test_util/counting_alloc.rs");
@@ -0,0 +1,2 @@
+mod unaligned_iter;
+mod policies;
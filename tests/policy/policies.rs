@@ -0,0 +1,110 @@
+use safe_transmute::{transmute_many_with_policy_with_guard, transmute_many_with_policy, PermissiveGuard, SingleManyGuard, PedanticGuard, ErrorReason,
+                     GuardError, Transmute, AlignOf, Aligned, Error};
+use safe_transmute::policy::{SkipPrefix, Unaligned, Strict};
+#[cfg(feature = "alloc")]
+use safe_transmute::policy::CopyOnMisalign;
+use safe_transmute::guard::ExactCount;
+#[cfg(feature = "alloc")]
+use alloc::borrow::Cow;
+
+
+#[test]
+fn strict() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x01, 0x00, 0x00, 0x00, 0x02]);
+    assert_eq!(Transmute::<u32>::from(&bytes.data[..]).guard::<PermissiveGuard>().on_unaligned(Strict).many(), Ok(&[u32::from_le(1)][..]));
+    match Transmute::<u32>::from(&bytes.data[1..]).guard::<PermissiveGuard>().on_unaligned(Strict).many() {
+        Err(Error::Unaligned(e)) => assert_eq!(e.offset, 3),
+        other => panic!("{:?}", other),
+    }
+}
+
+#[test]
+fn strict_guard() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00; 5]);
+    assert_eq!(Transmute::<u32>::from(&bytes.data[..]).guard::<PedanticGuard>().on_unaligned(Strict).many(),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn copy_on_misalign() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00]);
+    match Transmute::<u32>::from(&bytes.data[..4]).guard::<PedanticGuard>().on_unaligned(CopyOnMisalign).many().unwrap() {
+        Cow::Borrowed(words) => assert_eq!(words, [u32::from_le(1)]),
+        Cow::Owned(_) => panic!("aligned data copied"),
+    }
+    match Transmute::<u32>::from(&bytes.data[1..]).guard::<PermissiveGuard>().on_unaligned(CopyOnMisalign).many().unwrap() {
+        Cow::Owned(words) => assert_eq!(words, [u32::from_le(0x0100_0000)]),
+        Cow::Borrowed(_) => panic!("misaligned data borrowed"),
+    }
+    assert_eq!(Transmute::<u32>::from(&bytes.data[1..4]).guard::<SingleManyGuard>().on_unaligned(CopyOnMisalign).many(),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn skip_prefix() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00, 0xAA, 0xBB, 0xCC, 0x01, 0x00, 0x00, 0x00, 0xDD]);
+    for i in 1..4 {
+        assert_eq!(Transmute::<u32>::from(&bytes.data[i..]).guard::<PermissiveGuard>().on_unaligned(SkipPrefix).many(),
+                   Ok(&[u32::from_le(1)][..]));
+    }
+    assert_eq!(Transmute::<u32>::from(&bytes.data[1..8]).guard::<PedanticGuard>().on_unaligned(SkipPrefix).many(), Ok(&[u32::from_le(1)][..]));
+    assert_eq!(Transmute::<u32>::from(&bytes.data[1..]).guard::<PedanticGuard>().on_unaligned(SkipPrefix).many(), Ok(&[u32::from_le(1)][..]));
+    assert_eq!(Transmute::<u32>::from(&bytes.data[1..6]).guard::<PedanticGuard>().on_unaligned(SkipPrefix).many(),
+               Err(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn unaligned() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00]);
+    let words = Transmute::<u32>::from(&bytes.data[1..]).guard::<PedanticGuard>().on_unaligned(Unaligned).many().unwrap();
+    assert!(words.eq([u32::from_le(1), u32::from_le(2)].iter().cloned()));
+    assert_eq!(Transmute::<u32>::from(&bytes.data[2..]).guard::<PedanticGuard>().on_unaligned(Unaligned).many().err(),
+               Some(Error::Guard(GuardError {
+                   required: 32 / 8,
+                   actual: 7,
+                   reason: ErrorReason::InexactByteCount,
+               })));
+}
+
+#[test]
+fn with_guard() {
+    let bytes = Aligned::<AlignOf<u16>, _>::new([0x00, 0xFF, 0x01, 0x00, 0x02, 0x00]);
+    assert_eq!(Transmute::<u16>::from(&bytes.data[1..]).with_guard(ExactCount(2)).on_unaligned(SkipPrefix).many(),
+               Ok(&[u16::from_le(1), u16::from_le(2)][..]));
+    assert_eq!(Transmute::<u16>::from(&bytes.data[1..5]).with_guard(ExactCount(2)).on_unaligned(Unaligned).many().map(|words| words.len()),
+               Ok(2));
+    assert_eq!(Transmute::<u16>::from(&bytes.data[..2]).with_guard(ExactCount(2)).on_unaligned(Strict).many(),
+               Err(Error::Guard(GuardError {
+                   required: 2 * 16 / 8,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
+
+#[test]
+fn free_functions() {
+    let bytes = Aligned::<AlignOf<u16>, _>::new([0x00, 0xFF, 0x01, 0x00, 0x02, 0x00]);
+    assert_eq!(transmute_many_with_policy::<u16, PermissiveGuard, SkipPrefix>(&bytes.data[1..]),
+               Transmute::<u16>::from(&bytes.data[1..]).guard::<PermissiveGuard>().on_unaligned(SkipPrefix).many());
+    assert_eq!(transmute_many_with_policy_with_guard::<u16, _, Unaligned>(&bytes.data[1..5], &ExactCount(2)).map(|words| words.len()),
+               Ok(2));
+    assert_eq!(transmute_many_with_policy_with_guard::<u16, _, Strict>(&bytes.data[..2], &ExactCount(2)),
+               Err(Error::Guard(GuardError {
+                   required: 2 * 16 / 8,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               })));
+}
@@ -0,0 +1,44 @@
+use safe_transmute::{TriviallyTransmutable, UnalignedIter, AlignOf, Aligned};
+
+
+#[test]
+fn misaligned() {
+    let bytes = Aligned::<AlignOf<u32>, _>::new([0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0xFF]);
+    for (i, &first) in [0x0000_0001, 0x0200_0000, 0x0002_0000].iter().enumerate() {
+        let mut words = UnalignedIter::<u32>::new(&bytes.data[i + 1..]);
+        assert_eq!(words.len(), (9 - i) / 4);
+        assert_eq!(words.next(), Some(u32::from_le(first)));
+    }
+}
+
+#[test]
+fn trailing_bytes() {
+    let words = UnalignedIter::<u16>::new(&[0x01, 0x00, 0x02, 0x00, 0x03]);
+    assert_eq!(words.len(), 2);
+    assert_eq!(words.as_bytes(), &[0x01, 0x00, 0x02, 0x00]);
+    assert!(words.eq([u16::from_le(1), u16::from_le(2)].iter().cloned()));
+}
+
+#[test]
+fn double_ended() {
+    let mut words = UnalignedIter::<u16>::new(&[0x01, 0x00, 0x02, 0x00, 0x03, 0x00]);
+    assert_eq!(words.next_back(), Some(u16::from_le(3)));
+    assert_eq!(words.next(), Some(u16::from_le(1)));
+    assert_eq!(words.size_hint(), (1, Some(1)));
+    assert_eq!(words.as_bytes(), &[0x02, 0x00]);
+    assert_eq!(words.next_back(), Some(u16::from_le(2)));
+    assert_eq!(words.next(), None);
+    assert_eq!(words.next_back(), None);
+}
+
+#[test]
+fn zero_sized() {
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Marker;
+    unsafe impl TriviallyTransmutable for Marker {}
+
+    let mut units = UnalignedIter::<Marker>::new(&[0x00; 4]);
+    assert_eq!(units.len(), 0);
+    assert_eq!(units.next(), None);
+    assert_eq!(units.as_bytes(), &[]);
+}
@@ -0,0 +1,26 @@
+#[cfg(feature = "allocator_api")]
+use alloc::alloc::{AllocError, Allocator, Global, Layout};
+#[cfg(feature = "allocator_api")]
+use core::ptr::NonNull;
+#[cfg(feature = "allocator_api")]
+use core::cell::Cell;
+
+
+/// An allocator which forwards to `Global`, counting live allocations,
+/// so that tests can tell which allocator a vector lives in.
+#[cfg(feature = "allocator_api")]
+#[derive(Clone, Copy)]
+struct CountingAlloc<'a>(&'a Cell<isize>);
+
+#[cfg(feature = "allocator_api")]
+unsafe impl<'a> Allocator for CountingAlloc<'a> {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        self.0.set(self.0.get() + 1);
+        Global.allocate(layout)
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.0.set(self.0.get() - 1);
+        Global.deallocate(ptr, layout)
+    }
+}
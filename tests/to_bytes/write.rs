@@ -0,0 +1,114 @@
+use safe_transmute::{write_many_to_bytes_pedantic, write_to_bytes_pedantic, write_many_to_bytes, write_to_bytes, transmute_to_bytes_mut, transmute_to_bytes,
+                     ErrorReason, GuardError};
+
+
+#[test]
+fn one_too_short() {
+    let mut buffer = [0xFFu8; 3];
+    assert_eq!(write_to_bytes(&0u32, &mut buffer),
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(write_to_bytes_pedantic(&0u32, &mut buffer),
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 3,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert_eq!(buffer, [0xFF; 3]);
+}
+
+#[test]
+fn one_unaligned() {
+    let value = 0x0102_0304u32;
+    let mut buffer = [0u64; 2];
+    {
+        let buffer = &mut transmute_to_bytes_mut(&mut buffer)[1..];
+        assert_eq!(write_to_bytes(&value, buffer), Ok(4));
+        assert_eq!(write_to_bytes_pedantic(&value, &mut buffer[7..11]), Ok(4));
+    }
+    let bytes = transmute_to_bytes(&buffer);
+    assert_eq!(&bytes[1..5], transmute_to_bytes(&[value]));
+    assert_eq!(&bytes[8..12], transmute_to_bytes(&[value]));
+    assert_eq!(bytes[0], 0);
+    assert_eq!(bytes[5..8], [0, 0, 0]);
+    assert_eq!(bytes[12..], [0, 0, 0, 0]);
+}
+
+#[test]
+fn one_too_long_pedantic() {
+    let mut buffer = [0u8; 5];
+    assert_eq!(write_to_bytes_pedantic(&0x0102_0304u32, &mut buffer),
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert_eq!(buffer, [0; 5]);
+}
+
+#[test]
+fn many_too_short() {
+    let mut buffer = [0u8; 5];
+    assert_eq!(write_many_to_bytes(&[0u16, 1, 2], &mut buffer),
+               Err(GuardError {
+                   required: 6,
+                   actual: 5,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(buffer, [0; 5]);
+}
+
+#[test]
+fn many_just_enough() {
+    let words: &[u16] = &[0x0102, 0x0304, 0x0506];
+    let mut buffer = [0u8; 7];
+    assert_eq!(write_many_to_bytes(words, &mut buffer[1..]), Ok(6));
+    assert_eq!(&buffer[1..], transmute_to_bytes(words));
+    assert_eq!(write_many_to_bytes_pedantic(&words[1..], &mut buffer[..4]), Ok(4));
+    assert_eq!(&buffer[..4], transmute_to_bytes(&words[1..]));
+}
+
+#[test]
+fn many_empty() {
+    let mut buffer = [0u8; 2];
+    assert_eq!(write_many_to_bytes::<u16>(&[], &mut buffer), Ok(0));
+    assert_eq!(write_many_to_bytes::<u16>(&[], &mut []), Ok(0));
+    assert_eq!(write_many_to_bytes_pedantic::<u16>(&[], &mut []),
+               Err(GuardError {
+                   required: 16 / 8,
+                   actual: 0,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(write_many_to_bytes_pedantic::<u16>(&[], &mut buffer),
+               Err(GuardError {
+                   required: 16 / 8,
+                   actual: 2,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+}
+
+#[test]
+fn many_too_short_pedantic() {
+    let mut buffer = [0u8; 3];
+    assert_eq!(write_many_to_bytes_pedantic(&[0u16, 1], &mut buffer),
+               Err(GuardError {
+                   required: 4,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert_eq!(buffer, [0; 3]);
+}
+
+#[test]
+fn many_too_long_pedantic() {
+    let mut buffer = [0u8; 5];
+    assert_eq!(write_many_to_bytes_pedantic(&[0u16, 1], &mut buffer),
+               Err(GuardError {
+                   required: 4,
+                   actual: 5,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+}
@@ -0,0 +1,101 @@
+use safe_transmute::{transmute_uninit_to_bytes_mut, transmute_uninit_many_mut, transmute_to_bytes};
+use core::mem::{MaybeUninit, align_of};
+#[cfg(feature = "alloc")]
+use safe_transmute::{spare_capacity_bytes_mut, SingleManyGuard, PermissiveGuard, PedanticGuard, assume_init_bytes, ErrorReason, GuardError};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+
+#[test]
+fn to_bytes_and_back() {
+    let mut words = [MaybeUninit::<u32>::uninit(); 3];
+    {
+        let bytes = transmute_uninit_to_bytes_mut(&mut words);
+        assert_eq!(bytes.len(), 12);
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = MaybeUninit::new(i as u8);
+        }
+
+        let words = transmute_uninit_many_mut::<u32>(bytes).unwrap();
+        assert_eq!(words.len(), 3);
+    }
+
+    let words = [unsafe { words[0].assume_init() }, unsafe { words[1].assume_init() }, unsafe { words[2].assume_init() }];
+    assert_eq!(transmute_to_bytes(&words), &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+}
+
+#[test]
+fn many_rounds_down() {
+    let mut words = [MaybeUninit::<u32>::uninit(); 3];
+    let bytes = transmute_uninit_to_bytes_mut(&mut words);
+    assert_eq!(transmute_uninit_many_mut::<u32>(&mut bytes[..11]).unwrap().len(), 2);
+    assert_eq!(transmute_uninit_many_mut::<u16>(&mut bytes[..11]).unwrap().len(), 5);
+    assert_eq!(transmute_uninit_many_mut::<u32>(&mut bytes[..3]).unwrap().len(), 0);
+}
+
+#[test]
+fn many_unaligned() {
+    let mut words = [MaybeUninit::<u32>::uninit(); 3];
+    let bytes = transmute_uninit_to_bytes_mut(&mut words);
+    let err = transmute_uninit_many_mut::<u32>(&mut bytes[1..]).unwrap_err();
+    assert_eq!(err.offset, align_of::<u32>() - 1);
+    assert_eq!(err.source.len(), 11);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn spare_capacity() {
+    let mut words = Vec::<u32>::with_capacity(8);
+    words.push(0xFFFF_FFFF);
+    let capacity = words.capacity();
+    let spare = spare_capacity_bytes_mut(&mut words);
+    assert_eq!(spare.len(), (capacity - 1) * 4);
+    assert_eq!((spare.as_ptr() as usize) % align_of::<u32>(), 0);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn assume_init() {
+    let source: &[u32] = &[0x0102_0304, 0x0506_0708, 0x090A_0B0C];
+    let bytes = transmute_to_bytes(source);
+
+    let mut words = Vec::<u32>::with_capacity(3);
+    for (dst, &src) in spare_capacity_bytes_mut(&mut words).iter_mut().zip(&bytes[..10]) {
+        *dst = MaybeUninit::new(src);
+    }
+
+    assert_eq!(unsafe { assume_init_bytes::<u32, PedanticGuard>(&mut words, 10) },
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 10,
+                   reason: ErrorReason::InexactByteCount,
+               }));
+    assert!(words.is_empty());
+
+    assert_eq!(unsafe { assume_init_bytes::<u32, SingleManyGuard>(&mut words, 3) },
+               Err(GuardError {
+                   required: 32 / 8,
+                   actual: 3,
+                   reason: ErrorReason::NotEnoughBytes,
+               }));
+    assert!(words.is_empty());
+
+    assert_eq!(unsafe { assume_init_bytes::<u32, PermissiveGuard>(&mut words, 10) }, Ok(2));
+    assert_eq!(words, &source[..2]);
+
+    for (dst, &src) in spare_capacity_bytes_mut(&mut words).iter_mut().zip(&bytes[8..]) {
+        *dst = MaybeUninit::new(src);
+    }
+    assert_eq!(unsafe { assume_init_bytes::<u32, PedanticGuard>(&mut words, 4) }, Ok(1));
+    assert_eq!(words, source);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "alloc")]
+fn assume_init_too_much() {
+    let mut words = Vec::<u32>::with_capacity(1);
+    words.push(0);
+    let n = 4 * words.capacity();
+    let _ = unsafe { assume_init_bytes::<u32, PermissiveGuard>(&mut words, n) };
+}
@@ -0,0 +1,114 @@
+use safe_transmute::{transmute_many_skip_unaligned, transmute_many_permissive, transmute_many_pedantic,
+                     transmute_to_bytes_mut, TriviallyTransmutable, transmute_many_into, transmute_to_bytes, PermissiveGuard, SingleManyGuard, PedanticGuard,
+                     transmute_many_mut, transmute_one, transmute_many, ErrorReason, GuardError, Error};
+use safe_transmute::base::{transmute_many_permissive as transmute_many_permissive_unchecked, transmute_many as transmute_many_unchecked};
+use safe_transmute::trivial::transmute_trivial_many;
+#[cfg(feature = "alloc")]
+use safe_transmute::{transmute_many_permissive_or_copy, transmute_box_slice, transmute_rc_slice, extend_from_bytes, transmute_vec};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use safe_transmute::transmute_arc_slice;
+#[cfg(feature = "alloc")]
+use safe_transmute::base::transmute_vec as transmute_vec_unchecked;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::rc::Rc;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Marker;
+
+unsafe impl TriviallyTransmutable for Marker {}
+
+
+fn zero_sized_target(actual: usize) -> GuardError {
+    GuardError {
+        required: 0,
+        actual,
+        reason: ErrorReason::ZeroSizedTarget,
+    }
+}
+
+#[test]
+fn base() {
+    unsafe {
+        assert_eq!(transmute_many_unchecked::<(), PedanticGuard>(&[]), Err(Error::Guard(zero_sized_target(0))));
+        assert_eq!(transmute_many_unchecked::<(), SingleManyGuard>(&[0x00; 3]), Err(Error::Guard(zero_sized_target(3))));
+    }
+}
+
+#[test]
+#[should_panic]
+fn base_permissive() {
+    unsafe {
+        transmute_many_permissive_unchecked::<()>(&[0x00, 0x01]);
+    }
+}
+
+#[test]
+fn trivial() {
+    unsafe {
+        assert_eq!(transmute_trivial_many::<Marker, PermissiveGuard>(&[0x00; 4]), Err(Error::Guard(zero_sized_target(4))));
+    }
+}
+
+#[test]
+fn full() {
+    assert_eq!(transmute_one::<Marker>(&[]), Ok(Marker));
+    assert_eq!(transmute_many_permissive::<Marker>(&[0x00; 7]), Err(Error::Guard(zero_sized_target(7))));
+    assert_eq!(transmute_many::<Marker, SingleManyGuard>(&[]), Err(Error::Guard(zero_sized_target(0))));
+    assert_eq!(transmute_many_pedantic::<Marker>(&[0x00]), Err(Error::Guard(zero_sized_target(1))));
+    assert_eq!(transmute_many_mut::<Marker, PermissiveGuard>(&mut [0x00; 2]), Err(Error::Guard(zero_sized_target(2))));
+    assert_eq!(transmute_many_skip_unaligned::<Marker, PermissiveGuard>(&[0x00; 2]), Err(zero_sized_target(0)));
+
+    let mut markers = [Marker; 2];
+    assert_eq!(transmute_many_into::<Marker, PermissiveGuard>(&[0x00; 5], &mut markers), Err(zero_sized_target(5)));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic]
+fn full_permissive_or_copy() {
+    transmute_many_permissive_or_copy::<Marker>(&[0x00; 3]);
+}
+
+#[test]
+fn to_bytes() {
+    assert_eq!(transmute_to_bytes(&[Marker; 16]), &[]);
+    assert_eq!(transmute_to_bytes_mut(&mut [Marker; 16]), &mut []);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn vec() {
+    assert_eq!(transmute_vec::<Marker, Marker>(vec![Marker; 5]), Ok(vec![Marker; 5]));
+    unsafe {
+        assert_eq!(transmute_vec_unchecked::<(), Marker>(vec![(); 5]), vec![Marker; 5]);
+    }
+
+    match transmute_vec::<u8, Marker>(vec![0x00; 3]) {
+        Err(Error::IncompatibleVecTarget(_)) => {}
+        _ => panic!("expected an incompatible vector target error"),
+    }
+    match transmute_vec::<Marker, u8>(vec![Marker; 3]) {
+        Err(Error::IncompatibleVecTarget(e)) => assert_eq!(e.copy(), []),
+        _ => panic!("expected an incompatible vector target error"),
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn slices() {
+    assert_eq!(transmute_box_slice::<Marker, Marker>(vec![Marker; 2].into_boxed_slice()).unwrap().len(), 2);
+    assert_eq!(transmute_rc_slice::<Marker, Marker>(Rc::from(&[Marker; 3][..])).unwrap().len(), 3);
+    #[cfg(target_has_atomic = "ptr")]
+    assert_eq!(transmute_arc_slice::<Marker, Marker>(Arc::from(&[Marker; 4][..])).unwrap().len(), 4);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn extend() {
+    let mut markers = vec![Marker; 2];
+    assert_eq!(extend_from_bytes::<Marker, PermissiveGuard>(&mut markers, &[0x00; 3]), Err(Error::Guard(zero_sized_target(3))));
+    assert_eq!(markers.len(), 2);
+}
@@ -0,0 +1,76 @@
+use safe_transmute::{Zeroable, zeroed};
+use core::num::{NonZeroUsize, NonZeroU64, NonZeroI8};
+use core::ptr;
+#[cfg(feature = "alloc")]
+use safe_transmute::{zeroed_box, zeroed_vec};
+#[cfg(feature = "alloc")]
+use core::mem::align_of;
+
+
+#[repr(C)]
+#[derive(Debug, PartialEq, Eq)]
+struct Header {
+    id: Option<NonZeroU64>,
+    flag: bool,
+    tag: char,
+}
+
+unsafe impl Zeroable for Header {}
+
+
+#[test]
+fn trivially_transmutable() {
+    assert_eq!(zeroed::<u8>(), 0);
+    assert_eq!(zeroed::<i64>(), 0);
+    assert_eq!(zeroed::<f64>(), 0.0);
+    assert_eq!(zeroed::<[u32; 7]>(), [0; 7]);
+}
+
+#[test]
+fn zeroable() {
+    assert!(!zeroed::<bool>());
+    assert_eq!(zeroed::<char>(), '\0');
+    assert_eq!(zeroed::<Option<NonZeroI8>>(), None);
+    assert_eq!(zeroed::<Option<NonZeroUsize>>(), None);
+    assert_eq!(zeroed::<Option<&u32>>(), None);
+    assert_eq!(zeroed::<*const u32>(), ptr::null());
+    assert_eq!(zeroed::<*mut [u8; 4]>(), ptr::null_mut());
+    assert_eq!(zeroed::<*const [u16]>(), ptr::slice_from_raw_parts(ptr::null(), 0));
+    assert_eq!(zeroed::<Header>(),
+               Header {
+                   id: None,
+                   flag: false,
+                   tag: '\0',
+               });
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn boxed() {
+    assert_eq!(*zeroed_box::<u16>(), 0);
+    assert_eq!(*zeroed_box::<Option<NonZeroU64>>(), None);
+
+    let large = zeroed_box::<[[u64; 32]; 32]>();
+    assert_eq!((&*large as *const _ as usize) % align_of::<u64>(), 0);
+    assert!(large.iter().all(|row| row.iter().all(|&x| x == 0)));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn vec() {
+    assert!(zeroed_vec::<u32>(0).is_empty());
+
+    let words = zeroed_vec::<u64>(4096);
+    assert_eq!(words.len(), 4096);
+    assert_eq!((words.as_ptr() as usize) % align_of::<u64>(), 0);
+    assert!(words.iter().all(|&x| x == 0));
+
+    let mut headers = zeroed_vec::<Header>(3);
+    assert!(headers.iter().all(|h| *h == zeroed()));
+    headers.push(Header {
+        id: NonZeroU64::new(1),
+        flag: true,
+        tag: 'a',
+    });
+    assert_eq!(headers.len(), 4);
+}